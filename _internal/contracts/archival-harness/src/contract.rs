@@ -0,0 +1,36 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Symbol};
+
+#[contract]
+pub struct ArchivalHarness;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Entry(Symbol),
+}
+
+// Nothing here extends TTLs on write, so every entry starts at the network's
+// minimum persistent TTL and is archived as soon as that runs out.
+#[contractimpl]
+impl ArchivalHarness {
+    pub fn write_persistent(env: Env, key: Symbol, value: u64) {
+        env.storage().persistent().set(&DataKey::Entry(key), &value);
+    }
+
+    pub fn read_persistent(env: Env, key: Symbol) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Entry(key))
+    }
+
+    pub fn write_instance(env: Env, key: Symbol, value: u64) {
+        env.storage().instance().set(&DataKey::Entry(key), &value);
+    }
+
+    pub fn read_instance(env: Env, key: Symbol) -> Option<u64> {
+        env.storage().instance().get(&DataKey::Entry(key))
+    }
+
+    // Keeps the instance alive while persistent entries expire on their own
+    pub fn extend_instance(env: Env, extend_to: u32) {
+        env.storage().instance().extend_ttl(extend_to, extend_to);
+    }
+}
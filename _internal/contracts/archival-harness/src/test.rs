@@ -0,0 +1,113 @@
+#![cfg(test)]
+use crate::contract::{ArchivalHarness, ArchivalHarnessClient, DataKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Ledger as _},
+    xdr::{ContractDataDurability, LedgerKey},
+    Address, Env, String,
+};
+
+const CONTRACT_ID: &str = "CCQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2CNSG";
+const MIN_TTL: u32 = 100;
+
+fn register(env: &Env) -> ArchivalHarnessClient<'_> {
+    let id = Address::from_string(&String::from_str(env, CONTRACT_ID));
+    env.register_at(&id, ArchivalHarness, ());
+    ArchivalHarnessClient::new(env, &id)
+}
+
+fn setup() -> Env {
+    let env = Env::default();
+    env.ledger().set_min_persistent_entry_ttl(MIN_TTL);
+    env
+}
+
+// Mirrors a RestoreFootprint operation: archived persistent entries come back
+// with the minimum TTL and their data untouched.
+fn restore_archived(env: &Env) -> Env {
+    let mut snapshot = env.to_ledger_snapshot();
+    let sequence = snapshot.sequence_number;
+    let restored_until = sequence + snapshot.min_persistent_entry_ttl - 1;
+
+    for (key, (_, live_until)) in snapshot.ledger_entries.iter_mut() {
+        let persistent = match key.as_ref() {
+            LedgerKey::ContractData(data) => data.durability == ContractDataDurability::Persistent,
+            LedgerKey::ContractCode(_) => true,
+            _ => false,
+        };
+        if persistent && live_until.is_some_and(|l| l < sequence) {
+            *live_until = Some(restored_until);
+        }
+    }
+
+    Env::from_ledger_snapshot(snapshot)
+}
+
+// Reading an archived entry is a non-recoverable host error in tests, so
+// even a try_ call aborts
+#[test]
+#[should_panic(expected = "Error(Storage, InternalError)")]
+fn persistent_entry_expires_after_min_ttl() {
+    let env = setup();
+    let client = register(&env);
+    client.extend_instance(&10_000);
+
+    client.write_persistent(&symbol_short!("a"), &7);
+
+    env.ledger().set_sequence_number(MIN_TTL - 1);
+    assert_eq!(client.read_persistent(&symbol_short!("a")), Some(7));
+
+    env.ledger().set_sequence_number(MIN_TTL);
+    client.read_persistent(&symbol_short!("a"));
+}
+
+#[test]
+fn archived_persistent_entry_can_be_restored() {
+    let env = setup();
+    let client = register(&env);
+    client.extend_instance(&10_000);
+    client.write_persistent(&symbol_short!("a"), &7);
+
+    env.ledger().set_sequence_number(MIN_TTL * 2);
+
+    let restored = restore_archived(&env);
+    let client = register(&restored);
+
+    assert_eq!(client.read_persistent(&symbol_short!("a")), Some(7));
+    restored.as_contract(&client.address, || {
+        assert_eq!(
+            restored
+                .storage()
+                .persistent()
+                .get_ttl(&DataKey::Entry(symbol_short!("a"))),
+            MIN_TTL - 1
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Storage, InternalError)")]
+fn instance_data_expires_with_the_instance() {
+    let env = setup();
+    let client = register(&env);
+
+    client.write_instance(&symbol_short!("b"), &9);
+    assert_eq!(client.read_instance(&symbol_short!("b")), Some(9));
+
+    env.ledger().set_sequence_number(MIN_TTL);
+    client.read_instance(&symbol_short!("b"));
+}
+
+#[test]
+fn archived_instance_can_be_restored() {
+    let env = setup();
+    let client = register(&env);
+    client.write_instance(&symbol_short!("b"), &9);
+
+    env.ledger().set_sequence_number(MIN_TTL);
+
+    let restored = restore_archived(&env);
+    let client = register(&restored);
+
+    assert_eq!(client.read_instance(&symbol_short!("b")), Some(9));
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, symbol_short, vec, Address, Env, IntoVal, Symbol, Vec,
+};
+
+#[contract]
+pub struct AuthHarness;
+
+#[contractimpl]
+impl AuthHarness {
+    // Single address, auth over the invocation args
+    pub fn single(_env: Env, addr: Address) {
+        addr.require_auth();
+    }
+
+    // Single address, auth over args that differ from the invocation args
+    pub fn with_args(env: Env, addr: Address, amount: i128, memo: Symbol) {
+        addr.require_auth_for_args((symbol_short!("approve"), amount, memo).into_val(&env));
+    }
+
+    // One auth entry per address
+    pub fn multi(_env: Env, addrs: Vec<Address>) {
+        for addr in addrs.iter() {
+            addr.require_auth();
+        }
+    }
+
+    // Root and sub-invocation both require `addr`, producing a two level tree
+    pub fn nested(env: Env, addr: Address, callee: Address) {
+        addr.require_auth();
+        AuthHarnessClient::new(&env, &callee).single(&addr);
+    }
+
+    // Calls `target.single(addr)` without requiring any auth itself, so the
+    // auth tree has a non-root entry
+    pub fn relay(env: Env, addr: Address, target: Address) {
+        AuthHarnessClient::new(&env, &target).single(&addr);
+    }
+
+    // Pre-authorizes `target.single(current_contract)` so `relayer` can make
+    // that call on this contract's behalf
+    pub fn as_current_contract(env: Env, relayer: Address, target: Address) {
+        let current = env.current_contract_address();
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: target.clone(),
+                    fn_name: symbol_short!("single"),
+                    args: (current.clone(),).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+        AuthHarnessClient::new(&env, &relayer).relay(&current, &target);
+    }
+}
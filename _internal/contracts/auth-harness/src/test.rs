@@ -0,0 +1,218 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::contract::{AuthHarness, AuthHarnessClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    vec,
+    xdr::{ContractEventBody, ScAddress, ScVal},
+    Address, Env, IntoVal, Symbol,
+};
+
+fn invocation(
+    env: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: soroban_sdk::Vec<soroban_sdk::Val>,
+    sub_invocations: std::vec::Vec<AuthorizedInvocation>,
+) -> AuthorizedInvocation {
+    AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((contract.clone(), Symbol::new(env, fn_name), args)),
+        sub_invocations,
+    }
+}
+
+// Whether the diagnostic events show a call to `contract.fn_name`
+fn invoked(env: &Env, contract: &Address, fn_name: &str) -> bool {
+    let ScAddress::Contract(hash) = ScAddress::from(contract) else {
+        panic!("not a contract address");
+    };
+    let events = env.host().get_diagnostic_events().unwrap();
+    events.0.iter().any(|e| {
+        let ContractEventBody::V0(body) = &e.event.body;
+        matches!(
+            body.topics.as_slice(),
+            [ScVal::Symbol(topic), ScVal::Bytes(id), ScVal::Symbol(name)]
+                if topic.0.as_slice() == b"fn_call"
+                    && id.0.as_slice() == hash.0.as_slice()
+                    && name.0.as_slice() == fn_name.as_bytes()
+        )
+    })
+}
+
+#[test]
+fn single_require_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    client.single(&user);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            user.clone(),
+            invocation(
+                &env,
+                &id,
+                "single",
+                (user.clone(),).into_val(&env),
+                std::vec![]
+            ),
+        )]
+    );
+}
+
+#[test]
+fn require_auth_for_custom_args() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    client.with_args(&user, &100, &symbol_short!("memo"));
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            user.clone(),
+            invocation(
+                &env,
+                &id,
+                "with_args",
+                (symbol_short!("approve"), 100i128, symbol_short!("memo")).into_val(&env),
+                std::vec![],
+            ),
+        )]
+    );
+}
+
+#[test]
+fn multiple_addresses_in_one_call() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let addrs = vec![&env, a.clone(), b.clone()];
+
+    client.multi(&addrs);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(auths[0].0, a);
+    assert_eq!(auths[1].0, b);
+    assert_eq!(
+        auths[0].1,
+        invocation(
+            &env,
+            &id,
+            "multi",
+            (addrs.clone(),).into_val(&env),
+            std::vec![]
+        )
+    );
+}
+
+#[test]
+fn auth_in_sub_invocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(AuthHarness, ());
+    let callee = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    client.nested(&user, &callee);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            user.clone(),
+            invocation(
+                &env,
+                &id,
+                "nested",
+                (user.clone(), callee.clone()).into_val(&env),
+                std::vec![invocation(
+                    &env,
+                    &callee,
+                    "single",
+                    (user.clone(),).into_val(&env),
+                    std::vec![],
+                )],
+            ),
+        )]
+    );
+}
+
+#[test]
+fn relay_auth_is_non_root() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let id = env.register(AuthHarness, ());
+    let callee = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    client.relay(&user, &callee);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            user.clone(),
+            invocation(
+                &env,
+                &callee,
+                "single",
+                (user.clone(),).into_val(&env),
+                std::vec![]
+            ),
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn relay_requires_non_root_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register(AuthHarness, ());
+    let callee = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+
+    client.relay(&Address::generate(&env), &callee);
+}
+
+#[test]
+fn authorize_as_current_contract() {
+    let env = Env::default();
+    let id = env.register(AuthHarness, ());
+    let relayer = env.register(AuthHarness, ());
+    let target = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &id);
+
+    // No mocked auths: the call only succeeds because the contract
+    // pre-authorized the deeper `target.single` invocation
+    client.as_current_contract(&relayer, &target);
+
+    // `env.auths()` is only recorded for mocked auths, so check the call itself
+    assert!(invoked(&env, &target, "single"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn relay_without_contract_authorization_fails() {
+    let env = Env::default();
+    let id = env.register(AuthHarness, ());
+    let relayer = env.register(AuthHarness, ());
+    let target = env.register(AuthHarness, ());
+    let client = AuthHarnessClient::new(&env, &relayer);
+
+    client.relay(&id, &target);
+}
@@ -0,0 +1,208 @@
+use soroban_sdk::{contract, contracterror, contractimpl, Bytes, Env, I256, U256};
+
+#[contract]
+pub struct BigIntHarness;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+    DivisionByZero = 2,
+    ShiftTooLarge = 3,
+    InvalidLength = 4,
+}
+
+// The host traps on overflow, so every operation checks its operands first and
+// reports the failure as a typed error instead
+#[contractimpl]
+impl BigIntHarness {
+    // U256
+    pub fn u256_add(env: Env, a: U256, b: U256) -> Result<U256, Error> {
+        if a > u256_max(&env).sub(&b) {
+            return Err(Error::Overflow);
+        }
+        Ok(a.add(&b))
+    }
+    pub fn u256_sub(_env: Env, a: U256, b: U256) -> Result<U256, Error> {
+        if a < b {
+            return Err(Error::Overflow);
+        }
+        Ok(a.sub(&b))
+    }
+    pub fn u256_mul(env: Env, a: U256, b: U256) -> Result<U256, Error> {
+        u256_checked_mul(&env, &a, &b)
+    }
+    pub fn u256_div(env: Env, a: U256, b: U256) -> Result<U256, Error> {
+        if b == U256::from_u32(&env, 0) {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(a.div(&b))
+    }
+    pub fn u256_pow(env: Env, base: U256, exp: u32) -> Result<U256, Error> {
+        let mut result = U256::from_u32(&env, 1);
+        let mut base = base;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = u256_checked_mul(&env, &result, &base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = u256_checked_mul(&env, &base, &base)?;
+            }
+        }
+        Ok(result)
+    }
+    // Fails if any set bit is shifted out
+    pub fn u256_shl(_env: Env, a: U256, bits: u32) -> Result<U256, Error> {
+        if bits >= 256 {
+            return Err(Error::ShiftTooLarge);
+        }
+        let result = a.shl(bits);
+        if result.shr(bits) != a {
+            return Err(Error::Overflow);
+        }
+        Ok(result)
+    }
+    pub fn u256_shr(_env: Env, a: U256, bits: u32) -> Result<U256, Error> {
+        if bits >= 256 {
+            return Err(Error::ShiftTooLarge);
+        }
+        Ok(a.shr(bits))
+    }
+    // Big-endian, always 32 bytes
+    pub fn u256_to_be_bytes(_env: Env, a: U256) -> Bytes {
+        a.to_be_bytes()
+    }
+    pub fn u256_from_be_bytes(env: Env, b: Bytes) -> Result<U256, Error> {
+        if b.len() != 32 {
+            return Err(Error::InvalidLength);
+        }
+        Ok(U256::from_be_bytes(&env, &b))
+    }
+    pub fn u256_to_u128(_env: Env, a: U256) -> Result<u128, Error> {
+        a.to_u128().ok_or(Error::Overflow)
+    }
+
+    // I256
+    pub fn i256_add(env: Env, a: I256, b: I256) -> Result<I256, Error> {
+        let zero = I256::from_i32(&env, 0);
+        if (b > zero && a > i256_max(&env).sub(&b)) || (b < zero && a < i256_min(&env).sub(&b)) {
+            return Err(Error::Overflow);
+        }
+        Ok(a.add(&b))
+    }
+    pub fn i256_sub(env: Env, a: I256, b: I256) -> Result<I256, Error> {
+        let zero = I256::from_i32(&env, 0);
+        if (b < zero && a > i256_max(&env).add(&b)) || (b > zero && a < i256_min(&env).add(&b)) {
+            return Err(Error::Overflow);
+        }
+        Ok(a.sub(&b))
+    }
+    pub fn i256_mul(env: Env, a: I256, b: I256) -> Result<I256, Error> {
+        i256_checked_mul(&env, &a, &b)
+    }
+    // Truncates toward zero
+    pub fn i256_div(env: Env, a: I256, b: I256) -> Result<I256, Error> {
+        if b == I256::from_i32(&env, 0) {
+            return Err(Error::DivisionByZero);
+        }
+        if a == i256_min(&env) && b == I256::from_i32(&env, -1) {
+            return Err(Error::Overflow);
+        }
+        Ok(a.div(&b))
+    }
+    pub fn i256_pow(env: Env, base: I256, exp: u32) -> Result<I256, Error> {
+        let mut result = I256::from_i32(&env, 1);
+        let mut base = base;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = i256_checked_mul(&env, &result, &base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = i256_checked_mul(&env, &base, &base)?;
+            }
+        }
+        Ok(result)
+    }
+    // Fails if the value, including its sign, does not survive the shift
+    pub fn i256_shl(_env: Env, a: I256, bits: u32) -> Result<I256, Error> {
+        if bits >= 256 {
+            return Err(Error::ShiftTooLarge);
+        }
+        let result = a.shl(bits);
+        if result.shr(bits) != a {
+            return Err(Error::Overflow);
+        }
+        Ok(result)
+    }
+    // Arithmetic shift, extending the sign bit
+    pub fn i256_shr(_env: Env, a: I256, bits: u32) -> Result<I256, Error> {
+        if bits >= 256 {
+            return Err(Error::ShiftTooLarge);
+        }
+        Ok(a.shr(bits))
+    }
+    // Big-endian two's complement, always 32 bytes
+    pub fn i256_to_be_bytes(_env: Env, a: I256) -> Bytes {
+        a.to_be_bytes()
+    }
+    pub fn i256_from_be_bytes(env: Env, b: Bytes) -> Result<I256, Error> {
+        if b.len() != 32 {
+            return Err(Error::InvalidLength);
+        }
+        Ok(I256::from_be_bytes(&env, &b))
+    }
+    pub fn i256_to_i128(_env: Env, a: I256) -> Result<i128, Error> {
+        a.to_i128().ok_or(Error::Overflow)
+    }
+}
+
+fn u256_max(env: &Env) -> U256 {
+    U256::from_parts(env, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+}
+
+fn i256_max(env: &Env) -> I256 {
+    I256::from_parts(env, i64::MAX, u64::MAX, u64::MAX, u64::MAX)
+}
+
+fn i256_min(env: &Env) -> I256 {
+    I256::from_parts(env, i64::MIN, 0, 0, 0)
+}
+
+fn u256_checked_mul(env: &Env, a: &U256, b: &U256) -> Result<U256, Error> {
+    if *b != U256::from_u32(env, 0) && *a > u256_max(env).div(b) {
+        return Err(Error::Overflow);
+    }
+    Ok(a.mul(b))
+}
+
+// Compares `a` against MAX / b or MIN / b depending on the sign of the
+// product; division truncates toward zero, which makes each bound exact
+fn i256_checked_mul(env: &Env, a: &I256, b: &I256) -> Result<I256, Error> {
+    let zero = I256::from_i32(env, 0);
+    let minus_one = I256::from_i32(env, -1);
+    if *a == zero || *b == zero {
+        return Ok(zero);
+    }
+    if *b == minus_one {
+        if *a == i256_min(env) {
+            return Err(Error::Overflow);
+        }
+        return Ok(a.mul(b));
+    }
+
+    let overflow = match (*a > zero, *b > zero) {
+        (true, true) => *a > i256_max(env).div(b),
+        (false, false) => *a < i256_max(env).div(b),
+        (true, false) => *a > i256_min(env).div(b),
+        (false, true) => *a < i256_min(env).div(b),
+    };
+    if overflow {
+        return Err(Error::Overflow);
+    }
+    Ok(a.mul(b))
+}
@@ -0,0 +1,275 @@
+#![cfg(test)]
+use crate::contract::{BigIntHarness, BigIntHarnessClient, Error};
+use soroban_sdk::{Bytes, Env, I256, U256};
+
+fn setup() -> (Env, BigIntHarnessClient<'static>) {
+    let env = Env::default();
+    let id = env.register(BigIntHarness, ());
+    let client = BigIntHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+// 2^256 - 1
+fn u256_max(env: &Env) -> U256 {
+    U256::from_parts(env, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+}
+
+// 2^255
+fn u256_pow2_255(env: &Env) -> U256 {
+    U256::from_parts(env, 1 << 63, 0, 0, 0)
+}
+
+// 2^255 - 1
+fn i256_max(env: &Env) -> I256 {
+    I256::from_parts(env, i64::MAX, u64::MAX, u64::MAX, u64::MAX)
+}
+
+// -2^255
+fn i256_min(env: &Env) -> I256 {
+    I256::from_parts(env, i64::MIN, 0, 0, 0)
+}
+
+#[test]
+fn u256_add_sub_at_boundaries() {
+    let (env, client) = setup();
+    let max = u256_max(&env);
+    let zero = U256::from_u32(&env, 0);
+    let one = U256::from_u32(&env, 1);
+
+    assert_eq!(client.u256_add(&max.sub(&one), &one), max);
+    assert_eq!(client.try_u256_add(&max, &one), Err(Ok(Error::Overflow)));
+    assert_eq!(client.u256_sub(&max, &max), zero);
+    assert_eq!(client.try_u256_sub(&zero, &one), Err(Ok(Error::Overflow)));
+}
+
+#[test]
+fn u256_mul_div_pow_at_boundaries() {
+    let (env, client) = setup();
+    let max = u256_max(&env);
+    let two = U256::from_u32(&env, 2);
+    let pow2_128 = U256::from_parts(&env, 0, 1, 0, 0);
+    let pow2_128_minus_one = U256::from_u128(&env, u128::MAX);
+
+    // (2^128 - 1)(2^128 + 1) = 2^256 - 1
+    assert_eq!(
+        client.u256_mul(&pow2_128_minus_one, &pow2_128.add(&U256::from_u32(&env, 1))),
+        max
+    );
+    assert_eq!(
+        client.try_u256_mul(&pow2_128, &pow2_128),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.u256_mul(&max, &U256::from_u32(&env, 0)),
+        U256::from_u32(&env, 0)
+    );
+
+    assert_eq!(
+        client.u256_div(&max, &u256_pow2_255(&env)),
+        U256::from_u32(&env, 1)
+    );
+    assert_eq!(
+        client.try_u256_div(&max, &U256::from_u32(&env, 0)),
+        Err(Ok(Error::DivisionByZero))
+    );
+
+    assert_eq!(client.u256_pow(&two, &255), u256_pow2_255(&env));
+    assert_eq!(client.try_u256_pow(&two, &256), Err(Ok(Error::Overflow)));
+    assert_eq!(client.u256_pow(&max, &0), U256::from_u32(&env, 1));
+}
+
+#[test]
+fn u256_shifts() {
+    let (env, client) = setup();
+    let one = U256::from_u32(&env, 1);
+
+    assert_eq!(client.u256_shl(&one, &255), u256_pow2_255(&env));
+    assert_eq!(
+        client.try_u256_shl(&u256_pow2_255(&env), &1),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.try_u256_shl(&one, &256),
+        Err(Ok(Error::ShiftTooLarge))
+    );
+    assert_eq!(client.u256_shr(&u256_max(&env), &255), one);
+    assert_eq!(
+        client.try_u256_shr(&one, &256),
+        Err(Ok(Error::ShiftTooLarge))
+    );
+}
+
+#[test]
+fn u256_conversions() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.u256_to_be_bytes(&u256_max(&env)),
+        Bytes::from_array(&env, &[0xff; 32])
+    );
+    let mut top_bit = [0u8; 32];
+    top_bit[0] = 0x80;
+    assert_eq!(
+        client.u256_from_be_bytes(&Bytes::from_array(&env, &top_bit)),
+        u256_pow2_255(&env)
+    );
+    assert_eq!(
+        client.try_u256_from_be_bytes(&Bytes::from_array(&env, &[0xff; 31])),
+        Err(Ok(Error::InvalidLength))
+    );
+
+    assert_eq!(
+        client.u256_to_u128(&U256::from_u128(&env, u128::MAX)),
+        u128::MAX
+    );
+    assert_eq!(
+        client.try_u256_to_u128(&U256::from_parts(&env, 0, 1, 0, 0)),
+        Err(Ok(Error::Overflow))
+    );
+}
+
+#[test]
+fn i256_add_sub_at_boundaries() {
+    let (env, client) = setup();
+    let max = i256_max(&env);
+    let min = i256_min(&env);
+    let one = I256::from_i32(&env, 1);
+    let minus_one = I256::from_i32(&env, -1);
+
+    assert_eq!(client.i256_add(&max, &min), minus_one);
+    assert_eq!(client.try_i256_add(&max, &one), Err(Ok(Error::Overflow)));
+    assert_eq!(
+        client.try_i256_add(&min, &minus_one),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(client.i256_sub(&min, &min), I256::from_i32(&env, 0));
+    assert_eq!(client.try_i256_sub(&min, &one), Err(Ok(Error::Overflow)));
+    assert_eq!(
+        client.try_i256_sub(&max, &minus_one),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.try_i256_sub(&I256::from_i32(&env, 0), &min),
+        Err(Ok(Error::Overflow))
+    );
+}
+
+#[test]
+fn i256_mul_div_pow_at_boundaries() {
+    let (env, client) = setup();
+    let max = i256_max(&env);
+    let min = i256_min(&env);
+    let minus_one = I256::from_i32(&env, -1);
+    let two = I256::from_i32(&env, 2);
+    let minus_two = I256::from_i32(&env, -2);
+    let pow2_127 = I256::from_parts(&env, 0, 0, 1 << 63, 0);
+    let pow2_128 = I256::from_parts(&env, 0, 1, 0, 0);
+
+    // -2^127 * 2^128 = -2^255 fits, 2^127 * 2^128 does not
+    assert_eq!(client.i256_mul(&pow2_127.mul(&minus_one), &pow2_128), min);
+    assert_eq!(
+        client.try_i256_mul(&pow2_127, &pow2_128),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.i256_mul(&max, &minus_one),
+        min.add(&I256::from_i32(&env, 1))
+    );
+    assert_eq!(
+        client.try_i256_mul(&min, &minus_one),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.try_i256_mul(&minus_one, &min),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(client.try_i256_mul(&min, &two), Err(Ok(Error::Overflow)));
+
+    assert_eq!(
+        client.i256_div(&min, &two),
+        I256::from_parts(&env, -(1 << 62), 0, 0, 0)
+    );
+    assert_eq!(
+        client.i256_div(&I256::from_i32(&env, -7), &two),
+        I256::from_i32(&env, -3)
+    );
+    assert_eq!(
+        client.try_i256_div(&min, &minus_one),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(
+        client.try_i256_div(&max, &I256::from_i32(&env, 0)),
+        Err(Ok(Error::DivisionByZero))
+    );
+
+    assert_eq!(client.i256_pow(&minus_two, &255), min);
+    assert_eq!(client.try_i256_pow(&two, &255), Err(Ok(Error::Overflow)));
+    assert_eq!(
+        client.i256_pow(&two, &254),
+        I256::from_parts(&env, 1 << 62, 0, 0, 0)
+    );
+    assert_eq!(client.i256_pow(&minus_two, &3), I256::from_i32(&env, -8));
+}
+
+#[test]
+fn i256_shifts_extend_sign() {
+    let (env, client) = setup();
+    let min = i256_min(&env);
+    let one = I256::from_i32(&env, 1);
+    let minus_one = I256::from_i32(&env, -1);
+
+    assert_eq!(client.i256_shr(&min, &255), minus_one);
+    assert_eq!(client.i256_shr(&minus_one, &10), minus_one);
+    assert_eq!(client.i256_shr(&i256_max(&env), &254), one);
+    assert_eq!(client.i256_shl(&minus_one, &255), min);
+    assert_eq!(
+        client.i256_shl(&one, &254),
+        I256::from_parts(&env, 1 << 62, 0, 0, 0)
+    );
+    // Shifting into the sign bit flips the sign
+    assert_eq!(client.try_i256_shl(&one, &255), Err(Ok(Error::Overflow)));
+    assert_eq!(client.try_i256_shl(&min, &1), Err(Ok(Error::Overflow)));
+    assert_eq!(
+        client.try_i256_shr(&one, &256),
+        Err(Ok(Error::ShiftTooLarge))
+    );
+}
+
+#[test]
+fn i256_conversions() {
+    let (env, client) = setup();
+    let min = i256_min(&env);
+
+    assert_eq!(
+        client.i256_to_be_bytes(&I256::from_i32(&env, -1)),
+        Bytes::from_array(&env, &[0xff; 32])
+    );
+    let mut top_bit = [0u8; 32];
+    top_bit[0] = 0x80;
+    assert_eq!(
+        client.i256_to_be_bytes(&min),
+        Bytes::from_array(&env, &top_bit)
+    );
+    assert_eq!(
+        client.i256_from_be_bytes(&Bytes::from_array(&env, &top_bit)),
+        min
+    );
+    assert_eq!(
+        client.i256_from_be_bytes(&Bytes::from_array(&env, &[0xff; 32])),
+        I256::from_i32(&env, -1)
+    );
+    assert_eq!(
+        client.try_i256_from_be_bytes(&Bytes::from_array(&env, &[0xff; 33])),
+        Err(Ok(Error::InvalidLength))
+    );
+
+    assert_eq!(
+        client.i256_to_i128(&I256::from_i128(&env, i128::MIN)),
+        i128::MIN
+    );
+    assert_eq!(
+        client.try_i256_to_i128(&I256::from_i128(&env, i128::MIN).sub(&I256::from_i32(&env, 1))),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(client.try_i256_to_i128(&min), Err(Ok(Error::Overflow)));
+}
@@ -0,0 +1,158 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    crypto::bls12_381::{Fp, Fp2, Fr, G1Affine, G2Affine},
+    Bytes, BytesN, Env, Vec, U256,
+};
+
+#[contract]
+pub struct CryptoHarness;
+
+#[contractimpl]
+impl CryptoHarness {
+    // Hashes
+    pub fn sha256(env: Env, data: Bytes) -> BytesN<32> {
+        env.crypto().sha256(&data).into()
+    }
+    pub fn keccak256(env: Env, data: Bytes) -> BytesN<32> {
+        env.crypto().keccak256(&data).into()
+    }
+
+    // Signatures; the verify functions trap on an invalid signature
+    pub fn ed25519_verify(env: Env, public_key: BytesN<32>, message: Bytes, signature: BytesN<64>) {
+        env.crypto()
+            .ed25519_verify(&public_key, &message, &signature);
+    }
+    // Recovers the uncompressed public key that signed keccak256(message)
+    pub fn secp256k1_recover(
+        env: Env,
+        message: Bytes,
+        signature: BytesN<64>,
+        recovery_id: u32,
+    ) -> BytesN<65> {
+        let digest = env.crypto().keccak256(&message);
+        env.crypto()
+            .secp256k1_recover(&digest, &signature, recovery_id)
+    }
+    // Verifies a signature over sha256(message); `signature` must be low-S
+    pub fn secp256r1_verify(
+        env: Env,
+        public_key: BytesN<65>,
+        message: Bytes,
+        signature: BytesN<64>,
+    ) {
+        let digest = env.crypto().sha256(&message);
+        env.crypto()
+            .secp256r1_verify(&public_key, &digest, &signature);
+    }
+
+    // BLS12-381, with points and field elements in their uncompressed byte
+    // encodings and scalars as U256
+    pub fn bls_g1_add(env: Env, a: BytesN<96>, b: BytesN<96>) -> BytesN<96> {
+        env.crypto()
+            .bls12_381()
+            .g1_add(&G1Affine::from_bytes(a), &G1Affine::from_bytes(b))
+            .to_bytes()
+    }
+    pub fn bls_g1_mul(env: Env, point: BytesN<96>, scalar: U256) -> BytesN<96> {
+        env.crypto()
+            .bls12_381()
+            .g1_mul(&G1Affine::from_bytes(point), &Fr::from_u256(scalar))
+            .to_bytes()
+    }
+    pub fn bls_g1_msm(env: Env, points: Vec<BytesN<96>>, scalars: Vec<U256>) -> BytesN<96> {
+        let mut g1_points: Vec<G1Affine> = Vec::new(&env);
+        for point in points.iter() {
+            g1_points.push_back(G1Affine::from_bytes(point));
+        }
+        let mut frs: Vec<Fr> = Vec::new(&env);
+        for scalar in scalars.iter() {
+            frs.push_back(Fr::from_u256(scalar));
+        }
+        env.crypto().bls12_381().g1_msm(g1_points, frs).to_bytes()
+    }
+    pub fn bls_map_fp_to_g1(env: Env, fp: BytesN<48>) -> BytesN<96> {
+        env.crypto()
+            .bls12_381()
+            .map_fp_to_g1(&Fp::from_bytes(fp))
+            .to_bytes()
+    }
+    pub fn bls_hash_to_g1(env: Env, message: Bytes, dst: Bytes) -> BytesN<96> {
+        env.crypto()
+            .bls12_381()
+            .hash_to_g1(&message, &dst)
+            .to_bytes()
+    }
+    pub fn bls_g2_add(env: Env, a: BytesN<192>, b: BytesN<192>) -> BytesN<192> {
+        env.crypto()
+            .bls12_381()
+            .g2_add(&G2Affine::from_bytes(a), &G2Affine::from_bytes(b))
+            .to_bytes()
+    }
+    pub fn bls_g2_mul(env: Env, point: BytesN<192>, scalar: U256) -> BytesN<192> {
+        env.crypto()
+            .bls12_381()
+            .g2_mul(&G2Affine::from_bytes(point), &Fr::from_u256(scalar))
+            .to_bytes()
+    }
+    pub fn bls_g2_msm(env: Env, points: Vec<BytesN<192>>, scalars: Vec<U256>) -> BytesN<192> {
+        let mut g2_points: Vec<G2Affine> = Vec::new(&env);
+        for point in points.iter() {
+            g2_points.push_back(G2Affine::from_bytes(point));
+        }
+        let mut frs: Vec<Fr> = Vec::new(&env);
+        for scalar in scalars.iter() {
+            frs.push_back(Fr::from_u256(scalar));
+        }
+        env.crypto().bls12_381().g2_msm(g2_points, frs).to_bytes()
+    }
+    pub fn bls_map_fp2_to_g2(env: Env, fp2: BytesN<96>) -> BytesN<192> {
+        env.crypto()
+            .bls12_381()
+            .map_fp2_to_g2(&Fp2::from_bytes(fp2))
+            .to_bytes()
+    }
+    pub fn bls_hash_to_g2(env: Env, message: Bytes, dst: Bytes) -> BytesN<192> {
+        env.crypto()
+            .bls12_381()
+            .hash_to_g2(&message, &dst)
+            .to_bytes()
+    }
+    pub fn bls_pairing_check(env: Env, g1: Vec<BytesN<96>>, g2: Vec<BytesN<192>>) -> bool {
+        let mut g1_points: Vec<G1Affine> = Vec::new(&env);
+        for point in g1.iter() {
+            g1_points.push_back(G1Affine::from_bytes(point));
+        }
+        let mut g2_points: Vec<G2Affine> = Vec::new(&env);
+        for point in g2.iter() {
+            g2_points.push_back(G2Affine::from_bytes(point));
+        }
+        env.crypto().bls12_381().pairing_check(g1_points, g2_points)
+    }
+    pub fn bls_fr_add(env: Env, a: U256, b: U256) -> U256 {
+        env.crypto()
+            .bls12_381()
+            .fr_add(&Fr::from_u256(a), &Fr::from_u256(b))
+            .to_u256()
+    }
+    pub fn bls_fr_sub(env: Env, a: U256, b: U256) -> U256 {
+        env.crypto()
+            .bls12_381()
+            .fr_sub(&Fr::from_u256(a), &Fr::from_u256(b))
+            .to_u256()
+    }
+    pub fn bls_fr_mul(env: Env, a: U256, b: U256) -> U256 {
+        env.crypto()
+            .bls12_381()
+            .fr_mul(&Fr::from_u256(a), &Fr::from_u256(b))
+            .to_u256()
+    }
+    pub fn bls_fr_pow(env: Env, a: U256, exponent: u64) -> U256 {
+        env.crypto()
+            .bls12_381()
+            .fr_pow(&Fr::from_u256(a), exponent)
+            .to_u256()
+    }
+    pub fn bls_fr_inv(env: Env, a: U256) -> U256 {
+        env.crypto().bls12_381().fr_inv(&Fr::from_u256(a)).to_u256()
+    }
+}
@@ -0,0 +1,268 @@
+#![cfg(test)]
+use crate::contract::{CryptoHarness, CryptoHarnessClient};
+use soroban_sdk::{vec, Bytes, BytesN, Env, U256};
+
+const BLS_G1: &str = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+// Each Fp2 coordinate is encoded c1 || c0
+const BLS_G2: &str = "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801";
+// Scalar field order minus one, which negates a point
+const BLS_FR_MINUS_ONE: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000";
+
+const ED25519_PK: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const ED25519_SIG: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+fn setup() -> (Env, CryptoHarnessClient<'static>) {
+    let env = Env::default();
+    // Pairings and multi-scalar multiplications exceed the default budget
+    env.cost_estimate().budget().reset_unlimited();
+    let id = env.register(CryptoHarness, ());
+    let client = CryptoHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+fn decode<const N: usize>(hex: &str) -> [u8; N] {
+    assert_eq!(hex.len(), N * 2);
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+    }
+    out
+}
+
+fn bytes_n<const N: usize>(env: &Env, hex: &str) -> BytesN<N> {
+    BytesN::from_array(env, &decode::<N>(hex))
+}
+
+fn u256(env: &Env, hex: &str) -> U256 {
+    U256::from_be_bytes(env, &Bytes::from_array(env, &decode::<32>(hex)))
+}
+
+#[test]
+fn sha256_vectors() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.sha256(&Bytes::new(&env)),
+        bytes_n(
+            &env,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        )
+    );
+    assert_eq!(
+        client.sha256(&Bytes::from_slice(&env, b"abc")),
+        bytes_n(
+            &env,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        )
+    );
+}
+
+#[test]
+fn keccak256_vectors() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.keccak256(&Bytes::new(&env)),
+        bytes_n(
+            &env,
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        )
+    );
+    assert_eq!(
+        client.keccak256(&Bytes::from_slice(&env, b"abc")),
+        bytes_n(
+            &env,
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        )
+    );
+}
+
+// RFC 8032 section 7.1, tests 1 and 2
+#[test]
+fn ed25519_verify_vectors() {
+    let (env, client) = setup();
+
+    client.ed25519_verify(
+        &bytes_n(&env, ED25519_PK),
+        &Bytes::new(&env),
+        &bytes_n(&env, ED25519_SIG),
+    );
+    client.ed25519_verify(
+        &bytes_n(&env, "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"),
+        &Bytes::from_array(&env, &[0x72]),
+        &bytes_n(&env, "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"),
+    );
+}
+
+#[test]
+fn ed25519_verify_rejects_other_message() {
+    let (env, client) = setup();
+
+    let result = client.try_ed25519_verify(
+        &bytes_n(&env, ED25519_PK),
+        &Bytes::from_array(&env, &[0x72]),
+        &bytes_n(&env, ED25519_SIG),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn secp256k1_recover_vector() {
+    let (env, client) = setup();
+    let message = Bytes::from_slice(&env, b"abc");
+    let signature = bytes_n(&env, "cff9d6dfc1b1a6776b08418f8edf727468f91b68881ad2f9f1f3170f82aedb6802fb555c7aff861a5355fd7fc4f290a39461d4475e179f5ab043c4563ff9ab6f");
+
+    let recovered = client.secp256k1_recover(&message, &signature, &0);
+    assert_eq!(
+        recovered,
+        bytes_n(&env, "044e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de")
+    );
+
+    // The other recovery id yields a different key
+    assert_ne!(
+        client.secp256k1_recover(&message, &signature, &1),
+        recovered
+    );
+}
+
+// RFC 6979 appendix A.2.5, SHA-256 over "sample", with s negated to low-S
+#[test]
+fn secp256r1_verify_vector() {
+    let (env, client) = setup();
+    let public_key = bytes_n(&env, "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299");
+    let signature = bytes_n(&env, "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf37160834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9");
+
+    client.secp256r1_verify(&public_key, &Bytes::from_slice(&env, b"sample"), &signature);
+    assert!(client
+        .try_secp256r1_verify(&public_key, &Bytes::from_slice(&env, b"test"), &signature)
+        .is_err());
+}
+
+#[test]
+fn bls_group_operations() {
+    let (env, client) = setup();
+    let g1: BytesN<96> = bytes_n(&env, BLS_G1);
+    let g2: BytesN<192> = bytes_n(&env, BLS_G2);
+    let two = U256::from_u32(&env, 2);
+    let three = U256::from_u32(&env, 3);
+    let five = U256::from_u32(&env, 5);
+
+    assert_eq!(client.bls_g1_add(&g1, &g1), client.bls_g1_mul(&g1, &two));
+    assert_eq!(client.bls_g2_add(&g2, &g2), client.bls_g2_mul(&g2, &two));
+
+    // 2P + 3P = 5P
+    let scalars = vec![&env, two, three];
+    assert_eq!(
+        client.bls_g1_msm(&vec![&env, g1.clone(), g1.clone()], &scalars),
+        client.bls_g1_mul(&g1, &five)
+    );
+    assert_eq!(
+        client.bls_g2_msm(&vec![&env, g2.clone(), g2.clone()], &scalars),
+        client.bls_g2_mul(&g2, &five)
+    );
+}
+
+#[test]
+fn bls_pairing_is_bilinear() {
+    let (env, client) = setup();
+    let g1: BytesN<96> = bytes_n(&env, BLS_G1);
+    let g2: BytesN<192> = bytes_n(&env, BLS_G2);
+    let a = U256::from_u32(&env, 7);
+    let neg_g1 = client.bls_g1_mul(&g1, &u256(&env, BLS_FR_MINUS_ONE));
+
+    // e(aP, Q) * e(-P, aQ) == 1
+    assert!(client.bls_pairing_check(
+        &vec![&env, client.bls_g1_mul(&g1, &a), neg_g1],
+        &vec![&env, g2.clone(), client.bls_g2_mul(&g2, &a)],
+    ));
+    assert!(!client.bls_pairing_check(&vec![&env, g1], &vec![&env, g2]));
+}
+
+// RFC 9380 appendix J.9.1, msg = "abc"; `map_fp_to_g1` returns the
+// pre-cofactor-clearing points Q0 and Q1 for the intermediate u values
+#[test]
+fn bls_g1_hash_to_curve_vector() {
+    let (env, client) = setup();
+    let dst = Bytes::from_slice(&env, b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_");
+
+    assert_eq!(
+        client.bls_hash_to_g1(&Bytes::from_slice(&env, b"abc"), &dst),
+        bytes_n(&env, concat!(
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ))
+    );
+    assert_eq!(
+        client.bls_map_fp_to_g1(&bytes_n(&env, "0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951")),
+        bytes_n(&env, concat!(
+            "125435adce8e1cbd1c803e7123f45392dc6e326d292499c2c45c5865985fd74fe8f042ecdeeec5ecac80680d04317d80",
+            "0e8828948c989126595ee30e4f7c931cbd6f4570735624fd25aef2fa41d3f79cfb4b4ee7b7e55a8ce013af2a5ba20bf2",
+        ))
+    );
+    assert_eq!(
+        client.bls_map_fp_to_g1(&bytes_n(&env, "003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139")),
+        bytes_n(&env, concat!(
+            "11def93719829ecda3b46aa8c31fc3ac9c34b428982b898369608e4f042babee6c77ab9218aad5c87ba785481eff8ae4",
+            "0007c9cef122ccf2efd233d6eb9bfc680aa276652b0661f4f820a653cec1db7ff69899f8e52b8e92b025a12c822a6ce6",
+        ))
+    );
+}
+
+// RFC 9380 appendix J.10.1, msg = "abc"
+#[test]
+fn bls_g2_hash_to_curve_vector() {
+    let (env, client) = setup();
+    let dst = Bytes::from_slice(&env, b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_");
+
+    assert_eq!(
+        client.bls_hash_to_g2(&Bytes::from_slice(&env, b"abc"), &dst),
+        bytes_n(&env, concat!(
+            "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+            "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+            "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+            "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+        ))
+    );
+    assert_eq!(
+        client.bls_map_fp2_to_g2(&bytes_n(&env, concat!(
+            "01c8067bf4c0ba709aa8b9abc3d1cef589a4758e09ef53732d670fd8739a7274e111ba2fcaa71b3d33df2a3a0c8529dd",
+            "15f7c0aa8f6b296ab5ff9c2c7581ade64f4ee6f1bf18f55179ff44a2cf355fa53dd2a2158c5ecb17d7c52f63e7195771",
+        ))),
+        bytes_n(&env, concat!(
+            "05d8a724db78e570e34100c0bc4a5fa84ad5839359b40398151f37cff5a51de945c563463c9efbdda569850ee5a53e77",
+            "12b2e525281b5f4d2276954e84ac4f42cf4e13b6ac4228624e17760faf94ce5706d53f0ca1952f1c5ef75239aeed55ad",
+            "04bbe48bfd5814648d0b9e30f0717b34015d45a861425fabc1ee06fdfce36384ae2c808185e693ae97dcde118f34de41",
+            "02eacdc556d0bdb5d18d22f23dcb086dd106cad713777c7e6407943edbe0b3d1efe391eedf11e977fac55f9b94f2489c",
+        ))
+    );
+    assert_eq!(
+        client.bls_map_fp2_to_g2(&bytes_n(&env, concat!(
+            "08b852331c96ed983e497ebc6dee9b75e373d923b729194af8e72a051ea586f3538a6ebb1e80881a082fa2b24df9f566",
+            "187111d5e088b6b9acfdfad078c4dacf72dcd17ca17c82be35e79f8c372a693f60a033b461d81b025864a0ad051a06e4",
+        ))),
+        bytes_n(&env, concat!(
+            "15b0dadc256a258b4c68ea43605dffa6d312eef215c19e6474b3e101d33b661dfee43b51abbf96fee68fc6043ac56a58",
+            "19f18cc5ec0c2f055e47c802acc3b0e40c337256a208001dde14b25afced146f37ea3d3ce16834c78175b3ed61f3c537",
+            "19f98db2f4a1fcdf56a9ced7b320ea9deecf57c8e59236b0dc21f6ee7229aa9705ce9ac7fe7a31c72edca0d92370c096",
+            "05e47c1781286e61c7ade887512bd9c2cb9f640d3be9cf87ea0bad24bd0ebfe946497b48a581ab6c7d4ca74b5147287f",
+        ))
+    );
+}
+
+#[test]
+fn bls_scalar_field() {
+    let (env, client) = setup();
+    let minus_one = u256(&env, BLS_FR_MINUS_ONE);
+    let zero = U256::from_u32(&env, 0);
+    let one = U256::from_u32(&env, 1);
+    let seven = U256::from_u32(&env, 7);
+
+    assert_eq!(client.bls_fr_add(&minus_one, &one), zero);
+    assert_eq!(client.bls_fr_sub(&zero, &one), minus_one);
+    assert_eq!(client.bls_fr_mul(&minus_one, &minus_one), one);
+    assert_eq!(
+        client.bls_fr_pow(&U256::from_u32(&env, 2), &10),
+        U256::from_u32(&env, 1024)
+    );
+    assert_eq!(client.bls_fr_mul(&seven, &client.bls_fr_inv(&seven)), one);
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Val, Vec};
+
+#[contract]
+pub struct Deployer;
+
+#[contractimpl]
+impl Deployer {
+    // Deploys an instance of an uploaded wasm, addressed by this contract and `salt`
+    pub fn deploy(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        constructor_args: Vec<Val>,
+    ) -> Address {
+        env.deployer()
+            .with_current_contract(salt)
+            .deploy_v2(wasm_hash, constructor_args)
+    }
+
+    // Address `deploy` will produce for `salt`, whether or not it was deployed yet
+    pub fn deployed_address(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    }
+}
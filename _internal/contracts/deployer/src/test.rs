@@ -0,0 +1,110 @@
+#![cfg(test)]
+use crate::contract::{Deployer, DeployerClient};
+use soroban_sdk::{
+    testutils::Address as _,
+    vec,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, BytesN, Env, Error, IntoVal, String, Val, Vec,
+};
+
+// The harness exports ten-argument functions
+#[allow(clippy::too_many_arguments)]
+mod types_harness {
+    soroban_sdk::contractimport!(file = "../../tests/compiled-contracts/types_harness.wasm");
+}
+
+// The token wasm declares two `Metadata` types, which `contractimport!` cannot
+// express, so its client is declared by hand
+mod fungible_token {
+    use soroban_sdk::{contractclient, Address, Env, String};
+
+    pub const WASM: &[u8] =
+        include_bytes!("../../../tests/compiled-contracts/fungible_token_contract.wasm");
+
+    #[allow(dead_code)]
+    #[contractclient(name = "Client")]
+    pub trait Token {
+        fn name(env: Env) -> String;
+        fn get_owner(env: Env) -> Option<Address>;
+        fn balance(env: Env, account: Address) -> i128;
+    }
+}
+
+#[test]
+fn deployed_address_is_precomputed() {
+    let env = Env::default();
+    let id = env.register(Deployer, ());
+    let client = DeployerClient::new(&env, &id);
+    let wasm_hash = env.deployer().upload_contract_wasm(types_harness::WASM);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+
+    let expected = client.deployed_address(&salt);
+    let deployed = client.deploy(&wasm_hash, &salt, &vec![&env]);
+
+    assert_eq!(deployed, expected);
+    assert_ne!(
+        client.deployed_address(&BytesN::from_array(&env, &[8; 32])),
+        expected
+    );
+
+    let harness = types_harness::Client::new(&env, &deployed);
+    assert_eq!(harness.u32(&7), 7);
+}
+
+#[test]
+fn deployed_address_depends_on_deployer() {
+    let env = Env::default();
+    let a = DeployerClient::new(&env, &env.register(Deployer, ()));
+    let b = DeployerClient::new(&env, &env.register(Deployer, ()));
+    let salt = BytesN::from_array(&env, &[1; 32]);
+
+    assert_ne!(a.deployed_address(&salt), b.deployed_address(&salt));
+}
+
+#[test]
+fn deploys_token_with_constructor_args() {
+    let env = Env::default();
+    let id = env.register(Deployer, ());
+    let client = DeployerClient::new(&env, &id);
+    let wasm_hash = env.deployer().upload_contract_wasm(fungible_token::WASM);
+    let salt = BytesN::from_array(&env, &[1; 32]);
+    let recipient = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    let args: Vec<Val> = (
+        recipient.clone(),
+        owner.clone(),
+        String::from_str(&env, "ColibriToken"),
+        String::from_str(&env, "CLBT"),
+        7u32,
+        1_000i128,
+    )
+        .into_val(&env);
+    let deployed = client.deploy(&wasm_hash, &salt, &args);
+
+    let token = fungible_token::Client::new(&env, &deployed);
+    assert_eq!(token.name(), String::from_str(&env, "ColibriToken"));
+    assert_eq!(token.get_owner(), Some(owner));
+    assert_eq!(token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn same_salt_cannot_deploy_twice() {
+    let env = Env::default();
+    let id = env.register(Deployer, ());
+    let client = DeployerClient::new(&env, &id);
+    let wasm_hash = env.deployer().upload_contract_wasm(types_harness::WASM);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+
+    client.deploy(&wasm_hash, &salt, &vec![&env]);
+
+    // The host's "contract already exists" error reaches the caller narrowed
+    let err = client
+        .try_deploy(&wasm_hash, &salt, &vec![&env])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+    );
+}
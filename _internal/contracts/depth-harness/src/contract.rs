@@ -0,0 +1,74 @@
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, xdr::ToXdr, Address, Env, IntoVal, Vec,
+};
+
+#[contract]
+pub struct DepthHarness;
+
+// Same layout as `NestedType` in TypesHarness
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NestedType {
+    pub depth: u32,
+    pub width: u32,
+    pub nested: Vec<NestedType>,
+}
+
+#[contractimpl]
+impl DepthHarness {
+    // Calls `descend` on the first contract of `chain` with the rest of it, so
+    // each address adds one frame; returns the number of frames below this one.
+    // Contracts cannot re-enter themselves, so every address must be distinct
+    pub fn descend(env: Env, chain: Vec<Address>) -> u32 {
+        match chain.first() {
+            None => 0,
+            Some(next) => {
+                let rest = chain.slice(1..);
+                let below: u32 = env.invoke_contract(
+                    &next,
+                    &symbol_short!("descend"),
+                    vec![&env, rest.into_val(&env)],
+                );
+                below + 1
+            }
+        }
+    }
+
+    // Calls back into the current contract, which the host rejects
+    pub fn reenter(env: Env) {
+        let _: () = env.invoke_contract(
+            &env.current_contract_address(),
+            &symbol_short!("reenter"),
+            vec![&env],
+        );
+    }
+
+    // Builds a value `depth` levels deep. Each level is a map holding a vec, so
+    // 50 levels reach the host value depth limit of 100 and returning it fails
+    // when it is converted for the caller
+    pub fn nest(env: Env, depth: u32) -> NestedType {
+        build_nested(&env, depth)
+    }
+
+    // Serializes a value `depth` levels deep inside the contract, so the depth
+    // limit is hit by the host rather than the caller; returns the XDR length
+    pub fn nest_to_xdr(env: Env, depth: u32) -> u32 {
+        build_nested(&env, depth).to_xdr(&env).len()
+    }
+}
+
+fn build_nested(env: &Env, depth: u32) -> NestedType {
+    let mut v = NestedType {
+        depth: 0,
+        width: 0,
+        nested: Vec::new(env),
+    };
+    for d in 1..=depth {
+        v = NestedType {
+            depth: d,
+            width: 1,
+            nested: vec![env, v],
+        };
+    }
+    v
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::contract::{DepthHarness, DepthHarnessClient};
+use soroban_sdk::{
+    xdr::{ContractEventBody, ScError, ScErrorCode, ScVal},
+    Address, Env, Vec,
+};
+
+// Native frames are much larger than wasm ones, so deep chains need more
+// stack than the default test thread has
+fn with_large_stack(f: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+// The first error the host recorded in a diagnostic event; calls from the test
+// only see it narrowed to `Error(Context, InvalidAction)`
+fn raised_error(env: &Env) -> ScError {
+    let events = env.host().get_diagnostic_events().unwrap();
+    events
+        .0
+        .iter()
+        .find_map(|e| {
+            let ContractEventBody::V0(body) = &e.event.body;
+            match (body.topics.first(), body.topics.get(1)) {
+                (Some(ScVal::Symbol(topic)), Some(ScVal::Error(err)))
+                    if topic.0.as_slice() == b"error" =>
+                {
+                    Some(err.clone())
+                }
+                _ => None,
+            }
+        })
+        .expect("no error diagnostic event")
+}
+
+fn chain(env: &Env, len: u32) -> Vec<Address> {
+    let mut chain = Vec::new(env);
+    for _ in 0..len {
+        chain.push_back(env.register(DepthHarness, ()));
+    }
+    chain
+}
+
+#[test]
+fn descends_through_chain() {
+    let env = Env::default();
+    let client = DepthHarnessClient::new(&env, &env.register(DepthHarness, ()));
+
+    assert_eq!(client.descend(&Vec::new(&env)), 0);
+    assert_eq!(client.descend(&chain(&env, 10)), 10);
+}
+
+#[test]
+fn call_depth_limit_is_enforced() {
+    with_large_stack(|| {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let client = DepthHarnessClient::new(&env, &env.register(DepthHarness, ()));
+
+        assert_eq!(client.descend(&chain(&env, 50)), 50);
+
+        assert!(client.try_descend(&chain(&env, 150)).is_err());
+        assert_eq!(
+            raised_error(&env),
+            ScError::Context(ScErrorCode::ExceededLimit)
+        );
+    });
+}
+
+#[test]
+fn reentry_is_rejected() {
+    let env = Env::default();
+    let client = DepthHarnessClient::new(&env, &env.register(DepthHarness, ()));
+
+    assert!(client.try_reenter().is_err());
+    assert_eq!(
+        raised_error(&env),
+        ScError::Context(ScErrorCode::InvalidAction)
+    );
+}
+
+#[test]
+fn nest_builds_requested_depth() {
+    let env = Env::default();
+    let client = DepthHarnessClient::new(&env, &env.register(DepthHarness, ()));
+
+    let mut v = client.nest(&5);
+    let mut levels = 0;
+    while let Some(child) = v.nested.get(0) {
+        assert_eq!(v.depth, 5 - levels);
+        v = child;
+        levels += 1;
+    }
+    assert_eq!(levels, 5);
+}
+
+// Only the deepest value that fits is checked here; past the limit the native
+// test host takes exponential time to report the error, so that case is covered
+// by the contract integration tests in `core/contract`
+#[test]
+fn nest_to_xdr_fits_up_to_value_depth_limit() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = DepthHarnessClient::new(&env, &env.register(DepthHarness, ()));
+
+    assert!(client.nest_to_xdr(&49) > client.nest_to_xdr(&10));
+}
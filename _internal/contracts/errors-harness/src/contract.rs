@@ -0,0 +1,92 @@
+use soroban_sdk::{contract, contracterror, contractimpl, panic_with_error, Env};
+
+#[contract]
+pub struct ErrorsHarness;
+
+// The enums below reuse the same codes on purpose: a code on its own does not
+// identify the variant, only the code plus the enum it was raised from does
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    InsufficientBalance = 1,
+    InsufficientAllowance = 2,
+    InvalidAmount = 3,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessError {
+    Unauthorized = 1,
+    RoleNotGranted = 2,
+    OwnerNotSet = 3,
+}
+
+// Same variant name and code as `TokenError::InsufficientBalance`
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VaultError {
+    InsufficientBalance = 1,
+    Locked = 3,
+    Paused = 1000,
+}
+
+// Mirrors the OpenZeppelin pausable range, overlapping `VaultError::Paused`
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PauseError {
+    EnforcedPause = 1000,
+    ExpectedPause = 1001,
+}
+
+#[contractimpl]
+impl ErrorsHarness {
+    // Spec-declared errors, one function per enum; `code` 0 succeeds
+    pub fn token_error(_env: Env, code: u32) -> Result<(), TokenError> {
+        match code {
+            0 => Ok(()),
+            1 => Err(TokenError::InsufficientBalance),
+            2 => Err(TokenError::InsufficientAllowance),
+            3 => Err(TokenError::InvalidAmount),
+            _ => panic!("unknown error code"),
+        }
+    }
+    pub fn access_error(_env: Env, code: u32) -> Result<(), AccessError> {
+        match code {
+            0 => Ok(()),
+            1 => Err(AccessError::Unauthorized),
+            2 => Err(AccessError::RoleNotGranted),
+            3 => Err(AccessError::OwnerNotSet),
+            _ => panic!("unknown error code"),
+        }
+    }
+    pub fn vault_error(_env: Env, code: u32) -> Result<(), VaultError> {
+        match code {
+            0 => Ok(()),
+            1 => Err(VaultError::InsufficientBalance),
+            3 => Err(VaultError::Locked),
+            1000 => Err(VaultError::Paused),
+            _ => panic!("unknown error code"),
+        }
+    }
+    pub fn pause_error(_env: Env, code: u32) -> Result<(), PauseError> {
+        match code {
+            0 => Ok(()),
+            1000 => Err(PauseError::EnforcedPause),
+            1001 => Err(PauseError::ExpectedPause),
+            _ => panic!("unknown error code"),
+        }
+    }
+
+    // Raised by panic, so the spec of the function names no error enum
+    pub fn panic_vault_locked(env: Env) {
+        panic_with_error!(&env, VaultError::Locked);
+    }
+    pub fn panic_enforced_pause(env: Env) {
+        panic_with_error!(&env, PauseError::EnforcedPause);
+    }
+}
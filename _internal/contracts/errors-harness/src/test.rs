@@ -0,0 +1,75 @@
+#![cfg(test)]
+use crate::contract::{
+    AccessError, ErrorsHarness, ErrorsHarnessClient, PauseError, TokenError, VaultError,
+};
+use soroban_sdk::{Env, Error};
+
+fn setup() -> (Env, ErrorsHarnessClient<'static>) {
+    let env = Env::default();
+    let id = env.register(ErrorsHarness, ());
+    let client = ErrorsHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+#[test]
+fn each_enum_raises_its_variants() {
+    let (_env, client) = setup();
+
+    for e in [
+        TokenError::InsufficientBalance,
+        TokenError::InsufficientAllowance,
+        TokenError::InvalidAmount,
+    ] {
+        assert_eq!(client.try_token_error(&(e as u32)), Err(Ok(e)));
+    }
+    for e in [
+        AccessError::Unauthorized,
+        AccessError::RoleNotGranted,
+        AccessError::OwnerNotSet,
+    ] {
+        assert_eq!(client.try_access_error(&(e as u32)), Err(Ok(e)));
+    }
+    for e in [
+        VaultError::InsufficientBalance,
+        VaultError::Locked,
+        VaultError::Paused,
+    ] {
+        assert_eq!(client.try_vault_error(&(e as u32)), Err(Ok(e)));
+    }
+    for e in [PauseError::EnforcedPause, PauseError::ExpectedPause] {
+        assert_eq!(client.try_pause_error(&(e as u32)), Err(Ok(e)));
+    }
+
+    assert_eq!(client.try_token_error(&0), Ok(Ok(())));
+    assert_eq!(client.try_pause_error(&0), Ok(Ok(())));
+}
+
+#[test]
+fn colliding_codes_are_indistinguishable_on_the_wire() {
+    let (_env, client) = setup();
+
+    let token: Error = client.try_token_error(&1).unwrap_err().unwrap().into();
+    let access: Error = client.try_access_error(&1).unwrap_err().unwrap().into();
+    let vault: Error = client.try_vault_error(&1).unwrap_err().unwrap().into();
+    assert_eq!(token, Error::from_contract_error(1));
+    assert_eq!(token, access);
+    assert_eq!(token, vault);
+
+    let paused: Error = client.try_vault_error(&1000).unwrap_err().unwrap().into();
+    let enforced: Error = client.try_pause_error(&1000).unwrap_err().unwrap().into();
+    assert_eq!(paused, enforced);
+}
+
+#[test]
+fn panicked_errors_carry_only_the_code() {
+    let (_env, client) = setup();
+
+    assert_eq!(
+        client.try_panic_vault_locked(),
+        Err(Ok(Error::from_contract_error(3)))
+    );
+    assert_eq!(
+        client.try_panic_enforced_pause(),
+        Err(Ok(Error::from_contract_error(1000)))
+    );
+}
@@ -2,12 +2,38 @@
 // Compatible with OpenZeppelin Stellar Soroban Contracts ^0.4.1
 
 
-use soroban_sdk::{Address, contract, contractimpl, Env, String};
+use soroban_sdk::{
+    Address, contract, contracterror, contractimpl, contracttype, Env, panic_with_error, String,
+    Symbol,
+};
 use stellar_access::ownable::{self as ownable, Ownable};
 use stellar_contract_utils::pausable::{self as pausable, Pausable};
 use stellar_contract_utils::upgradeable::UpgradeableInternal;
 use stellar_macros::{default_impl, only_owner, Upgradeable, when_not_paused};
-use stellar_tokens::fungible::{Base, burnable::FungibleBurnable, FungibleToken};
+use stellar_tokens::fungible::{
+    Base, burnable::FungibleBurnable, FungibleToken, ALLOW_BLOCK_EXTEND_AMOUNT,
+    ALLOW_BLOCK_TTL_THRESHOLD,
+};
+
+/// Upper bound for `decimals`; anything above leaves too little headroom in an `i128` balance.
+pub const MAX_DECIMALS: u32 = 18;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ColibriTokenError {
+    InvalidDecimals = 3000,
+    EmptyName = 3001,
+    EmptySymbol = 3002,
+    NegativeInitialSupply = 3003,
+    AccountDeauthorized = 3004,
+}
+
+#[contracttype]
+pub enum ColibriTokenStorageKey {
+    Admin,
+    Authorized(Address),
+}
 
 #[derive(Upgradeable)]
 #[contract]
@@ -15,17 +41,50 @@ pub struct ColibriToken;
 
 #[contractimpl]
 impl ColibriToken {
-    pub fn __constructor(e: &Env, recipient: Address, owner: Address) {
-        Base::set_metadata(e, 18, String::from_str(e, "ColibriToken"), String::from_str(e, "CLBT"));
-        Base::mint(e, &recipient, 100000000000000000000000);
+    pub fn __constructor(
+        e: &Env,
+        recipient: Address,
+        owner: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+    ) {
+        if decimals > MAX_DECIMALS {
+            panic_with_error!(e, ColibriTokenError::InvalidDecimals);
+        }
+        if name.is_empty() {
+            panic_with_error!(e, ColibriTokenError::EmptyName);
+        }
+        if symbol.is_empty() {
+            panic_with_error!(e, ColibriTokenError::EmptySymbol);
+        }
+        if initial_supply < 0 {
+            panic_with_error!(e, ColibriTokenError::NegativeInitialSupply);
+        }
+
+        Base::set_metadata(e, decimals, name, symbol);
+        if initial_supply > 0 {
+            Base::mint(e, &recipient, initial_supply);
+        }
         ownable::set_owner(e, &owner);
+        e.storage().instance().set(&ColibriTokenStorageKey::Admin, &owner);
     }
 
     #[only_owner]
     #[when_not_paused]
     pub fn mint(e: &Env, account: Address, amount: i128) {
+        require_authorized(e, &account);
         Base::mint(e, &account, amount);
     }
+
+    pub fn spendable_balance(e: &Env, id: Address) -> i128 {
+        if is_authorized(e, &id) {
+            Base::balance(e, &id)
+        } else {
+            0
+        }
+    }
 }
 
 #[default_impl]
@@ -35,11 +94,15 @@ impl FungibleToken for ColibriToken {
 
     #[when_not_paused]
     fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        require_authorized(e, &from);
+        require_authorized(e, &to);
         Self::ContractType::transfer(e, &from, &to, amount);
     }
 
     #[when_not_paused]
     fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        require_authorized(e, &from);
+        require_authorized(e, &to);
         Self::ContractType::transfer_from(e, &spender, &from, &to, amount);
     }
 }
@@ -52,15 +115,76 @@ impl FungibleToken for ColibriToken {
 impl FungibleBurnable for ColibriToken {
     #[when_not_paused]
     fn burn(e: &Env, from: Address, amount: i128) {
+        require_authorized(e, &from);
         Base::burn(e, &from, amount);
     }
 
     #[when_not_paused]
     fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        require_authorized(e, &from);
         Base::burn_from(e, &spender, &from, amount);
     }
 }
 
+//
+// Admin
+//
+
+#[contractimpl]
+impl ColibriToken {
+    pub fn admin(e: &Env) -> Address {
+        read_admin(e)
+    }
+
+    pub fn set_admin(e: &Env, new_admin: Address) {
+        let admin = read_admin(e);
+        admin.require_auth();
+
+        e.storage().instance().set(&ColibriTokenStorageKey::Admin, &new_admin);
+        e.events().publish((Symbol::new(e, "set_admin"), admin), new_admin);
+    }
+
+    pub fn authorized(e: &Env, id: Address) -> bool {
+        is_authorized(e, &id)
+    }
+
+    #[when_not_paused]
+    pub fn set_authorized(e: &Env, id: Address, authorize: bool) {
+        read_admin(e).require_auth();
+
+        let key = ColibriTokenStorageKey::Authorized(id.clone());
+        e.storage().persistent().set(&key, &authorize);
+        e.storage().persistent().extend_ttl(&key, ALLOW_BLOCK_TTL_THRESHOLD, ALLOW_BLOCK_EXTEND_AMOUNT);
+        e.events().publish((Symbol::new(e, "set_authorized"), id), authorize);
+    }
+
+    #[when_not_paused]
+    pub fn clawback(e: &Env, from: Address, amount: i128) {
+        read_admin(e).require_auth();
+
+        Base::update(e, Some(&from), None, amount);
+        e.events().publish((Symbol::new(e, "clawback"), from), amount);
+    }
+}
+
+fn read_admin(e: &Env) -> Address {
+    e.storage().instance().get(&ColibriTokenStorageKey::Admin).unwrap()
+}
+
+/// Accounts are authorized unless the admin has explicitly revoked them.
+fn is_authorized(e: &Env, id: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&ColibriTokenStorageKey::Authorized(id.clone()))
+        .unwrap_or(true)
+}
+
+fn require_authorized(e: &Env, id: &Address) {
+    if !is_authorized(e, id) {
+        panic_with_error!(e, ColibriTokenError::AccountDeauthorized);
+    }
+}
+
 //
 // Utils
 //
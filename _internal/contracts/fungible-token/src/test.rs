@@ -2,18 +2,226 @@
 
 extern crate std;
 
-use soroban_sdk::{ testutils::Address as _, Address, Env, String };
+use soroban_sdk::{
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, AuthorizedInvocation, Events,
+    },
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+use stellar_tokens::fungible::ALLOW_BLOCK_EXTEND_AMOUNT;
 
-use crate::contract::{ ColibriToken, ColibriTokenClient };
+use crate::contract::{ ColibriToken, ColibriTokenClient, ColibriTokenStorageKey };
+
+fn create_token<'a>(
+    env: &'a Env,
+    recipient: &Address,
+    owner: &Address,
+    name: &str,
+    symbol: &str,
+    decimals: u32,
+    initial_supply: i128,
+) -> ColibriTokenClient<'a> {
+    let contract_addr = env.register(
+        ColibriToken,
+        (
+            recipient.clone(),
+            owner.clone(),
+            String::from_str(env, name),
+            String::from_str(env, symbol),
+            decimals,
+            initial_supply,
+        ),
+    );
+    ColibriTokenClient::new(env, &contract_addr)
+}
 
 #[test]
 fn initial_state() {
     let env = Env::default();
+    let recipient = Address::generate(&env);
 
-    let contract_addr = env.register(ColibriToken, (Address::generate(&env),Address::generate(&env)));
-    let client = ColibriTokenClient::new(&env, &contract_addr);
+    let client = create_token(&env, &recipient, &Address::generate(&env), "ColibriToken", "CLBT", 18, 100000000000000000000000);
 
     assert_eq!(client.name(), String::from_str(&env, "ColibriToken"));
+    assert_eq!(client.symbol(), String::from_str(&env, "CLBT"));
+    assert_eq!(client.decimals(), 18);
+    assert_eq!(client.balance(&recipient), 100000000000000000000000);
+    assert_eq!(client.total_supply(), 100000000000000000000000);
+}
+
+#[test]
+fn custom_decimals() {
+    let env = Env::default();
+    let recipient = Address::generate(&env);
+
+    for decimals in [0, 7, 18] {
+        let client = create_token(&env, &recipient, &Address::generate(&env), "Custom", "CSTM", decimals, 1);
+        assert_eq!(client.decimals(), decimals);
+    }
+}
+
+#[test]
+fn zero_initial_supply_mints_nothing() {
+    let env = Env::default();
+    let recipient = Address::generate(&env);
+
+    let client = create_token(&env, &recipient, &Address::generate(&env), "Empty", "EMPT", 7, 0);
+
+    assert_eq!(client.balance(&recipient), 0);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3000)")]
+fn rejects_too_many_decimals() {
+    let env = Env::default();
+    create_token(&env, &Address::generate(&env), &Address::generate(&env), "ColibriToken", "CLBT", 19, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3001)")]
+fn rejects_empty_name() {
+    let env = Env::default();
+    create_token(&env, &Address::generate(&env), &Address::generate(&env), "", "CLBT", 7, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3002)")]
+fn rejects_empty_symbol() {
+    let env = Env::default();
+    create_token(&env, &Address::generate(&env), &Address::generate(&env), "ColibriToken", "", 7, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3003)")]
+fn rejects_negative_initial_supply() {
+    let env = Env::default();
+    create_token(&env, &Address::generate(&env), &Address::generate(&env), "ColibriToken", "CLBT", 7, -1);
 }
 
-// Add more tests bellow
+fn assert_admin_auth(env: &Env, client: &ColibriTokenClient, admin: &Address, fn_name: &str, args: Vec<Val>) {
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((client.address.clone(), Symbol::new(env, fn_name), args)),
+                sub_invocations: std::vec![],
+            },
+        )]
+    );
+}
+
+#[test]
+fn owner_is_initial_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let client = create_token(&env, &Address::generate(&env), &owner, "ColibriToken", "CLBT", 7, 1_000);
+
+    assert_eq!(client.admin(), owner);
+
+    client.set_admin(&new_admin);
+    assert_admin_auth(&env, &client, &owner, "set_admin", (new_admin.clone(),).into_val(&env));
+    let (_, topics, data) = env.events().all().last().unwrap();
+
+    assert_eq!(client.admin(), new_admin);
+    let expected: Vec<Val> = (Symbol::new(&env, "set_admin"), owner.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let emitted: Address = data.into_val(&env);
+    assert_eq!(emitted, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn set_admin_requires_admin_auth() {
+    let env = Env::default();
+    let client = create_token(&env, &Address::generate(&env), &Address::generate(&env), "ColibriToken", "CLBT", 7, 1_000);
+
+    client.set_admin(&Address::generate(&env));
+}
+
+#[test]
+fn deauthorized_account_cannot_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let client = create_token(&env, &holder, &owner, "ColibriToken", "CLBT", 7, 1_000);
+
+    assert!(client.authorized(&holder));
+    client.set_authorized(&holder, &false);
+    assert_admin_auth(&env, &client, &owner, "set_authorized", (holder.clone(), false).into_val(&env));
+
+    assert!(!client.authorized(&holder));
+    assert_eq!(client.balance(&holder), 1_000);
+    assert_eq!(client.spendable_balance(&holder), 0);
+    assert!(client.try_transfer(&holder, &receiver, &1).is_err());
+
+    client.set_authorized(&holder, &true);
+    client.transfer(&holder, &receiver, &1);
+    assert_eq!(client.balance(&receiver), 1);
+}
+
+#[test]
+fn set_authorized_extends_entry_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let client = create_token(&env, &holder, &Address::generate(&env), "ColibriToken", "CLBT", 7, 1_000);
+
+    client.set_authorized(&holder, &false);
+
+    let ttl = env.as_contract(&client.address, || {
+        env.storage().persistent().get_ttl(&ColibriTokenStorageKey::Authorized(holder.clone()))
+    });
+    assert_eq!(ttl, ALLOW_BLOCK_EXTEND_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3004)")]
+fn deauthorized_account_cannot_receive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let client = create_token(&env, &holder, &Address::generate(&env), "ColibriToken", "CLBT", 7, 1_000);
+
+    client.set_authorized(&receiver, &false);
+    client.transfer(&holder, &receiver, &1);
+}
+
+#[test]
+fn admin_claws_back_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let client = create_token(&env, &holder, &owner, "ColibriToken", "CLBT", 7, 1_000);
+
+    client.clawback(&holder, &400);
+    assert_admin_auth(&env, &client, &owner, "clawback", (holder.clone(), 400i128).into_val(&env));
+    let (_, topics, data) = env.events().all().last().unwrap();
+
+    assert_eq!(client.balance(&holder), 600);
+    assert_eq!(client.total_supply(), 600);
+    let expected: Vec<Val> = (Symbol::new(&env, "clawback"), holder.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+    let amount: i128 = data.into_val(&env);
+    assert_eq!(amount, 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn clawback_is_blocked_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let client = create_token(&env, &holder, &owner, "ColibriToken", "CLBT", 7, 1_000);
+
+    client.pause(&owner);
+    client.clawback(&holder, &1);
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env};
+
+#[contract]
+pub struct LedgerHarness;
+
+// Everything the other functions return, read in a single invocation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnvInfo {
+    pub sequence: u32,
+    pub timestamp: u64,
+    pub network_id: BytesN<32>,
+    pub max_live_until_ledger: u32,
+    pub protocol_version: u32,
+    pub contract: Address,
+}
+
+#[contractimpl]
+impl LedgerHarness {
+    pub fn sequence(env: Env) -> u32 {
+        env.ledger().sequence()
+    }
+    pub fn timestamp(env: Env) -> u64 {
+        env.ledger().timestamp()
+    }
+    // sha256 of the network passphrase
+    pub fn network_id(env: Env) -> BytesN<32> {
+        env.ledger().network_id()
+    }
+    // Last ledger an entry extended now could live until: sequence + max TTL - 1
+    pub fn max_live_until_ledger(env: Env) -> u32 {
+        env.ledger().max_live_until_ledger()
+    }
+    pub fn protocol_version(env: Env) -> u32 {
+        env.ledger().protocol_version()
+    }
+    pub fn current_address(env: Env) -> Address {
+        env.current_contract_address()
+    }
+    pub fn info(env: Env) -> EnvInfo {
+        EnvInfo {
+            sequence: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            network_id: env.ledger().network_id(),
+            max_live_until_ledger: env.ledger().max_live_until_ledger(),
+            protocol_version: env.ledger().protocol_version(),
+            contract: env.current_contract_address(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+use crate::contract::{EnvInfo, LedgerHarness, LedgerHarnessClient};
+use soroban_sdk::{
+    testutils::{Ledger as _, LedgerInfo},
+    Bytes, BytesN, Env,
+};
+
+const TESTNET_PASSPHRASE: &[u8] = b"Test SDF Network ; September 2015";
+const TESTNET_NETWORK_ID: [u8; 32] = [
+    0xce, 0xe0, 0x30, 0x2d, 0x59, 0x84, 0x4d, 0x32, 0xbd, 0xca, 0x91, 0x5c, 0x82, 0x03, 0xdd, 0x44,
+    0xb3, 0x3f, 0xbb, 0x7e, 0xdc, 0x19, 0x05, 0x1e, 0xa3, 0x7a, 0xbe, 0xdf, 0x28, 0xec, 0xd4, 0x72,
+];
+
+fn setup() -> (Env, LedgerHarnessClient<'static>) {
+    let env = Env::default();
+    let id = env.register(LedgerHarness, ());
+    let client = LedgerHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+#[test]
+fn reads_individually_set_values() {
+    let (env, client) = setup();
+
+    env.ledger().set_sequence_number(1234);
+    env.ledger().set_timestamp(1_725_000_000);
+    env.ledger().set_protocol_version(22);
+    env.ledger().set_network_id(TESTNET_NETWORK_ID);
+
+    assert_eq!(client.sequence(), 1234);
+    assert_eq!(client.timestamp(), 1_725_000_000);
+    assert_eq!(client.protocol_version(), 22);
+    assert_eq!(
+        client.network_id(),
+        BytesN::from_array(&env, &TESTNET_NETWORK_ID)
+    );
+    assert_eq!(client.current_address(), client.address);
+}
+
+#[test]
+fn network_id_is_passphrase_hash() {
+    let (env, client) = setup();
+
+    let hash = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, TESTNET_PASSPHRASE));
+    env.ledger().set_network_id(hash.to_array());
+
+    assert_eq!(
+        client.network_id(),
+        BytesN::from_array(&env, &TESTNET_NETWORK_ID)
+    );
+}
+
+#[test]
+fn max_live_until_follows_sequence_and_max_ttl() {
+    let (env, client) = setup();
+
+    // Set the raw host value; the `set_max_entry_ttl` helper excludes the current ledger
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1000;
+        li.max_entry_ttl = 5000;
+    });
+    assert_eq!(client.max_live_until_ledger(), 5999);
+
+    env.ledger().set_sequence_number(2000);
+    assert_eq!(client.max_live_until_ledger(), 6999);
+}
+
+#[test]
+fn info_matches_ledger() {
+    let (env, client) = setup();
+
+    env.ledger().set(LedgerInfo {
+        protocol_version: 22,
+        sequence_number: 42,
+        timestamp: 12345,
+        network_id: TESTNET_NETWORK_ID,
+        base_reserve: 10,
+        min_temp_entry_ttl: 16,
+        min_persistent_entry_ttl: 4096,
+        max_entry_ttl: 6_312_000,
+    });
+
+    assert_eq!(
+        client.info(),
+        EnvInfo {
+            sequence: 42,
+            timestamp: 12345,
+            network_id: BytesN::from_array(&env, &TESTNET_NETWORK_ID),
+            max_live_until_ledger: 42 + 6_312_000 - 1,
+            protocol_version: 22,
+            contract: client.address.clone(),
+        }
+    );
+}
@@ -0,0 +1,171 @@
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype,
+    crypto::Hash,
+    panic_with_error, BytesN, Env, Vec,
+};
+
+#[contract]
+pub struct MultisigAccount;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signer {
+    pub public_key: BytesN<32>,
+    pub weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Signer(BytesN<32>),
+    TotalWeight,
+    Threshold,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountError {
+    InvalidThreshold = 1,
+    InvalidWeight = 2,
+    SignerAlreadyExists = 3,
+    UnknownSigner = 4,
+    BadSignatureOrder = 5,
+    ThresholdNotMet = 6,
+    WeightOverflow = 7,
+}
+
+#[contractimpl]
+impl MultisigAccount {
+    pub fn __constructor(env: Env, signers: Vec<Signer>, threshold: u32) {
+        for signer in signers.iter() {
+            add_signer_entry(&env, &signer);
+        }
+        write_threshold(&env, threshold);
+    }
+
+    pub fn signer_weight(env: Env, public_key: BytesN<32>) -> Option<u32> {
+        env.storage().instance().get(&DataKey::Signer(public_key))
+    }
+
+    pub fn threshold(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Threshold).unwrap()
+    }
+
+    // Admin functions, authorized by the wallet itself through `__check_auth`
+    pub fn add_signer(env: Env, signer: Signer) {
+        env.current_contract_address().require_auth();
+        add_signer_entry(&env, &signer);
+    }
+
+    pub fn remove_signer(env: Env, public_key: BytesN<32>) {
+        env.current_contract_address().require_auth();
+
+        let key = DataKey::Signer(public_key);
+        let weight: u32 = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, AccountError::UnknownSigner));
+        let total = total_weight(&env) - weight;
+        if total < Self::threshold(env.clone()) {
+            panic_with_error!(&env, AccountError::InvalidThreshold);
+        }
+
+        env.storage().instance().remove(&key);
+        env.storage().instance().set(&DataKey::TotalWeight, &total);
+    }
+
+    pub fn set_threshold(env: Env, threshold: u32) {
+        env.current_contract_address().require_auth();
+        write_threshold(&env, threshold);
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MultisigAccount {
+    type Signature = Vec<Signature>;
+    type Error = AccountError;
+
+    // Signatures must be sorted by public key so each signer is counted once
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Signature>,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), AccountError> {
+        let mut weight = 0u32;
+        let mut previous: Option<BytesN<32>> = None;
+
+        for signature in signatures.iter() {
+            if let Some(previous) = previous {
+                if previous >= signature.public_key {
+                    return Err(AccountError::BadSignatureOrder);
+                }
+            }
+
+            let signer_weight: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::Signer(signature.public_key.clone()))
+                .ok_or(AccountError::UnknownSigner)?;
+            env.crypto().ed25519_verify(
+                &signature.public_key,
+                &signature_payload.clone().into(),
+                &signature.signature,
+            );
+
+            weight = weight
+                .checked_add(signer_weight)
+                .ok_or(AccountError::WeightOverflow)?;
+            previous = Some(signature.public_key);
+        }
+
+        if weight < Self::threshold(env) {
+            return Err(AccountError::ThresholdNotMet);
+        }
+        Ok(())
+    }
+}
+
+fn total_weight(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalWeight)
+        .unwrap_or(0)
+}
+
+fn add_signer_entry(env: &Env, signer: &Signer) {
+    if signer.weight == 0 {
+        panic_with_error!(env, AccountError::InvalidWeight);
+    }
+    let key = DataKey::Signer(signer.public_key.clone());
+    if env.storage().instance().has(&key) {
+        panic_with_error!(env, AccountError::SignerAlreadyExists);
+    }
+
+    let total = total_weight(env)
+        .checked_add(signer.weight)
+        .unwrap_or_else(|| panic_with_error!(env, AccountError::WeightOverflow));
+
+    env.storage().instance().set(&key, &signer.weight);
+    env.storage().instance().set(&DataKey::TotalWeight, &total);
+}
+
+fn write_threshold(env: &Env, threshold: u32) {
+    if threshold == 0 || threshold > total_weight(env) {
+        panic_with_error!(env, AccountError::InvalidThreshold);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::Threshold, &threshold);
+}
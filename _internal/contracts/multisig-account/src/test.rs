@@ -0,0 +1,207 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::contract::{AccountError, MultisigAccount, MultisigAccountClient, Signature, Signer};
+use ed25519_dalek::{Signer as _, SigningKey};
+use soroban_sdk::{
+    auth::Context,
+    testutils::BytesN as _,
+    vec,
+    xdr::{ScErrorCode, ScErrorType},
+    BytesN, Env, Error, IntoVal, InvokeError, Vec,
+};
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn sign(env: &Env, payload: &BytesN<32>, keys: &[&SigningKey]) -> Vec<Signature> {
+    let mut keys = std::vec::Vec::from(keys);
+    keys.sort_by_key(|k| k.verifying_key().to_bytes());
+
+    let mut signatures = Vec::new(env);
+    for key in keys {
+        signatures.push_back(Signature {
+            public_key: public_key(env, key),
+            signature: BytesN::from_array(env, &key.sign(&payload.to_array()).to_bytes()),
+        });
+    }
+    signatures
+}
+
+fn create_account<'a>(
+    env: &'a Env,
+    keys: &[(&SigningKey, u32)],
+    threshold: u32,
+) -> MultisigAccountClient<'a> {
+    let mut signers = Vec::new(env);
+    for (key, weight) in keys {
+        signers.push_back(Signer {
+            public_key: public_key(env, key),
+            weight: *weight,
+        });
+    }
+    let id = env.register(MultisigAccount, (signers, threshold));
+    MultisigAccountClient::new(env, &id)
+}
+
+fn check_auth(
+    env: &Env,
+    client: &MultisigAccountClient,
+    payload: &BytesN<32>,
+    signatures: Vec<Signature>,
+) -> Result<(), Result<AccountError, InvokeError>> {
+    let contexts: Vec<Context> = vec![env];
+    env.try_invoke_contract_check_auth::<AccountError>(
+        &client.address,
+        payload,
+        signatures.into_val(env),
+        &contexts,
+    )
+}
+
+#[test]
+fn constructor_stores_signers_and_threshold() {
+    let env = Env::default();
+    let (a, b) = (signing_key(1), signing_key(2));
+    let client = create_account(&env, &[(&a, 1), (&b, 2)], 2);
+
+    assert_eq!(client.threshold(), 2);
+    assert_eq!(client.signer_weight(&public_key(&env, &a)), Some(1));
+    assert_eq!(client.signer_weight(&public_key(&env, &b)), Some(2));
+    assert_eq!(client.signer_weight(&BytesN::random(&env)), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn constructor_rejects_unreachable_threshold() {
+    let env = Env::default();
+    create_account(&env, &[(&signing_key(1), 1)], 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn constructor_rejects_total_weight_overflow() {
+    let env = Env::default();
+    create_account(
+        &env,
+        &[(&signing_key(1), u32::MAX), (&signing_key(2), 1)],
+        1,
+    );
+}
+
+#[test]
+fn check_auth_accepts_weight_at_threshold() {
+    let env = Env::default();
+    let (a, b, c) = (signing_key(1), signing_key(2), signing_key(3));
+    let client = create_account(&env, &[(&a, 1), (&b, 1), (&c, 1)], 2);
+    let payload = BytesN::random(&env);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, sign(&env, &payload, &[&a, &c])),
+        Ok(())
+    );
+    assert_eq!(
+        check_auth(&env, &client, &payload, sign(&env, &payload, &[&a, &b, &c])),
+        Ok(())
+    );
+}
+
+#[test]
+fn check_auth_rejects_weight_below_threshold() {
+    let env = Env::default();
+    let (a, b) = (signing_key(1), signing_key(2));
+    let client = create_account(&env, &[(&a, 1), (&b, 1)], 2);
+    let payload = BytesN::random(&env);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, sign(&env, &payload, &[&a])),
+        Err(Ok(AccountError::ThresholdNotMet))
+    );
+}
+
+#[test]
+fn check_auth_rejects_unknown_signer() {
+    let env = Env::default();
+    let (a, stranger) = (signing_key(1), signing_key(9));
+    let client = create_account(&env, &[(&a, 1)], 1);
+    let payload = BytesN::random(&env);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, sign(&env, &payload, &[&stranger])),
+        Err(Ok(AccountError::UnknownSigner))
+    );
+}
+
+#[test]
+fn check_auth_rejects_duplicate_or_unsorted_signatures() {
+    let env = Env::default();
+    let a = signing_key(1);
+    let client = create_account(&env, &[(&a, 1)], 1);
+    let payload = BytesN::random(&env);
+
+    let once = sign(&env, &payload, &[&a]);
+    let mut twice = once.clone();
+    twice.append(&once);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, twice),
+        Err(Ok(AccountError::BadSignatureOrder))
+    );
+}
+
+#[test]
+fn check_auth_rejects_signature_over_other_payload() {
+    let env = Env::default();
+    let a = signing_key(1);
+    let client = create_account(&env, &[(&a, 1)], 1);
+    let payload = BytesN::random(&env);
+    let other = BytesN::random(&env);
+
+    assert!(check_auth(&env, &client, &payload, sign(&env, &other, &[&a])).is_err());
+}
+
+#[test]
+fn admin_functions_require_wallet_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (a, b) = (signing_key(1), signing_key(2));
+    let client = create_account(&env, &[(&a, 1)], 1);
+
+    client.add_signer(&Signer {
+        public_key: public_key(&env, &b),
+        weight: 3,
+    });
+    assert_eq!(env.auths()[0].0, client.address);
+    assert_eq!(client.signer_weight(&public_key(&env, &b)), Some(3));
+
+    client.set_threshold(&4);
+    assert_eq!(env.auths()[0].0, client.address);
+    assert_eq!(client.threshold(), 4);
+
+    assert_eq!(
+        client.try_remove_signer(&public_key(&env, &b)),
+        Err(Ok(AccountError::InvalidThreshold.into()))
+    );
+
+    client.set_threshold(&1);
+    client.remove_signer(&public_key(&env, &b));
+    assert_eq!(client.signer_weight(&public_key(&env, &b)), None);
+}
+
+#[test]
+fn admin_functions_fail_without_wallet_auth() {
+    let env = Env::default();
+    let client = create_account(&env, &[(&signing_key(1), 1)], 1);
+
+    // The missing wallet signature reaches the caller as a narrowed host error
+    let err = client.try_set_threshold(&1).unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+    );
+}
@@ -0,0 +1,157 @@
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype,
+    crypto::Hash,
+    panic_with_error, Bytes, BytesN, Env, Vec,
+};
+
+use crate::webauthn;
+
+#[contract]
+pub struct PasskeyAccount;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub credential_id: Bytes,
+    pub authenticator_data: Bytes,
+    pub client_data_json: Bytes,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Credential(Bytes),
+    CredentialCount,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountError {
+    CredentialAlreadyExists = 1,
+    UnknownCredential = 2,
+    LastCredential = 3,
+    InvalidClientData = 4,
+    ChallengeMismatch = 5,
+    UserNotPresent = 6,
+    InvalidAuthenticatorData = 7,
+    NotAnAssertion = 8,
+}
+
+#[contractimpl]
+impl PasskeyAccount {
+    pub fn __constructor(env: Env, credential_id: Bytes, public_key: BytesN<65>) {
+        add_credential_entry(&env, credential_id, public_key);
+    }
+
+    pub fn credential(env: Env, credential_id: Bytes) -> Option<BytesN<65>> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Credential(credential_id))
+    }
+
+    // Admin functions, authorized by the wallet itself through `__check_auth`
+    pub fn add_credential(env: Env, credential_id: Bytes, public_key: BytesN<65>) {
+        env.current_contract_address().require_auth();
+        add_credential_entry(&env, credential_id, public_key);
+    }
+
+    pub fn remove_credential(env: Env, credential_id: Bytes) {
+        env.current_contract_address().require_auth();
+
+        let key = DataKey::Credential(credential_id);
+        if !env.storage().instance().has(&key) {
+            panic_with_error!(&env, AccountError::UnknownCredential);
+        }
+        let count = credential_count(&env);
+        if count == 1 {
+            panic_with_error!(&env, AccountError::LastCredential);
+        }
+
+        env.storage().instance().remove(&key);
+        env.storage()
+            .instance()
+            .set(&DataKey::CredentialCount, &(count - 1));
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for PasskeyAccount {
+    type Signature = Signature;
+    type Error = AccountError;
+
+    // Verifies a WebAuthn assertion whose challenge is the signature payload
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signature: Signature,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), AccountError> {
+        let public_key: BytesN<65> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Credential(signature.credential_id))
+            .ok_or(AccountError::UnknownCredential)?;
+
+        let client_data_len = signature.client_data_json.len() as usize;
+        if client_data_len > webauthn::CLIENT_DATA_JSON_MAX_LEN {
+            return Err(AccountError::InvalidClientData);
+        }
+        let mut client_data = [0u8; webauthn::CLIENT_DATA_JSON_MAX_LEN];
+        signature
+            .client_data_json
+            .copy_into_slice(&mut client_data[..client_data_len]);
+        if !webauthn::is_assertion(&client_data[..client_data_len]) {
+            return Err(AccountError::NotAnAssertion);
+        }
+        let challenge = webauthn::extract_challenge(&client_data[..client_data_len])
+            .ok_or(AccountError::InvalidClientData)?;
+        if challenge != webauthn::encode_challenge(&signature_payload.to_array()) {
+            return Err(AccountError::ChallengeMismatch);
+        }
+
+        let authenticator_data_len = signature.authenticator_data.len() as usize;
+        if authenticator_data_len < webauthn::AUTHENTICATOR_DATA_MIN_LEN {
+            return Err(AccountError::InvalidAuthenticatorData);
+        }
+        let mut authenticator_data = [0u8; webauthn::AUTHENTICATOR_DATA_MIN_LEN];
+        signature
+            .authenticator_data
+            .slice(..webauthn::AUTHENTICATOR_DATA_MIN_LEN as u32)
+            .copy_into_slice(&mut authenticator_data);
+        if !webauthn::user_present(&authenticator_data) {
+            return Err(AccountError::UserNotPresent);
+        }
+
+        // The authenticator signs authenticatorData || sha256(clientDataJSON)
+        let mut message = signature.authenticator_data.clone();
+        message.append(&env.crypto().sha256(&signature.client_data_json).into());
+        let digest = env.crypto().sha256(&message);
+        env.crypto()
+            .secp256r1_verify(&public_key, &digest, &signature.signature);
+
+        Ok(())
+    }
+}
+
+fn credential_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CredentialCount)
+        .unwrap_or(0)
+}
+
+fn add_credential_entry(env: &Env, credential_id: Bytes, public_key: BytesN<65>) {
+    let key = DataKey::Credential(credential_id);
+    if env.storage().instance().has(&key) {
+        panic_with_error!(env, AccountError::CredentialAlreadyExists);
+    }
+
+    env.storage().instance().set(&key, &public_key);
+    env.storage()
+        .instance()
+        .set(&DataKey::CredentialCount, &(credential_count(env) + 1));
+}
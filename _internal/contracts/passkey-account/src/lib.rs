@@ -0,0 +1,4 @@
+#![no_std]
+mod contract;
+mod test;
+mod webauthn;
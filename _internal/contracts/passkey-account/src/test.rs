@@ -0,0 +1,235 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::contract::{AccountError, PasskeyAccount, PasskeyAccountClient, Signature};
+use crate::webauthn;
+use p256::ecdsa::{signature::Signer as _, Signature as P256Signature, SigningKey};
+use soroban_sdk::{
+    auth::Context, testutils::BytesN as _, vec, Bytes, BytesN, Env, IntoVal, InvokeError, Vec,
+};
+
+struct Passkey {
+    credential_id: Bytes,
+    signing_key: SigningKey,
+}
+
+impl Passkey {
+    fn new(env: &Env, seed: u8) -> Self {
+        Passkey {
+            credential_id: Bytes::from_array(env, &[seed; 16]),
+            signing_key: SigningKey::from_slice(&[seed; 32]).unwrap(),
+        }
+    }
+
+    fn public_key(&self, env: &Env) -> BytesN<65> {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(env, point.as_bytes().try_into().unwrap())
+    }
+
+    // Mimics `navigator.credentials.get` with `payload` as the challenge
+    fn assert(&self, env: &Env, payload: &BytesN<32>, flags: u8) -> Signature {
+        let challenge = webauthn::encode_challenge(&payload.to_array());
+        let mut client_data_json =
+            Bytes::from_slice(env, b"{\"type\":\"webauthn.get\",\"challenge\":\"");
+        client_data_json.extend_from_slice(&challenge);
+        client_data_json.extend_from_slice(b"\",\"origin\":\"https://colibri.test\"}");
+
+        let mut authenticator_data = [0u8; 37];
+        authenticator_data[32] = flags;
+        let authenticator_data = Bytes::from_array(env, &authenticator_data);
+
+        self.sign(env, authenticator_data, client_data_json)
+    }
+
+    fn sign(&self, env: &Env, authenticator_data: Bytes, client_data_json: Bytes) -> Signature {
+        let mut message = authenticator_data.clone();
+        message.append(&env.crypto().sha256(&client_data_json).into());
+        let mut buf = std::vec![0u8; message.len() as usize];
+        message.copy_into_slice(&mut buf);
+
+        let signature: P256Signature = self.signing_key.sign(&buf);
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        Signature {
+            credential_id: self.credential_id.clone(),
+            authenticator_data,
+            client_data_json,
+            signature: BytesN::from_array(env, &signature.to_bytes()[..].try_into().unwrap()),
+        }
+    }
+}
+
+fn create_account<'a>(env: &'a Env, passkey: &Passkey) -> PasskeyAccountClient<'a> {
+    let id = env.register(
+        PasskeyAccount,
+        (passkey.credential_id.clone(), passkey.public_key(env)),
+    );
+    PasskeyAccountClient::new(env, &id)
+}
+
+fn check_auth(
+    env: &Env,
+    client: &PasskeyAccountClient,
+    payload: &BytesN<32>,
+    signature: Signature,
+) -> Result<(), Result<AccountError, InvokeError>> {
+    let contexts: Vec<Context> = vec![env];
+    env.try_invoke_contract_check_auth::<AccountError>(
+        &client.address,
+        payload,
+        signature.into_val(env),
+        &contexts,
+    )
+}
+
+#[test]
+fn challenge_is_unpadded_base64_url() {
+    let mut payload = [0u8; 32];
+    payload[0] = 0xfb;
+    payload[31] = 0xff;
+
+    let encoded = webauthn::encode_challenge(&payload);
+
+    assert_eq!(&encoded, b"-wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8");
+}
+
+#[test]
+fn check_auth_accepts_valid_assertion() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let signature = passkey.assert(&env, &payload, 0x05);
+
+    assert_eq!(check_auth(&env, &client, &payload, signature), Ok(()));
+}
+
+#[test]
+fn check_auth_rejects_challenge_for_other_payload() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let signature = passkey.assert(&env, &BytesN::random(&env), 0x05);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, signature),
+        Err(Ok(AccountError::ChallengeMismatch))
+    );
+}
+
+#[test]
+fn check_auth_rejects_missing_challenge() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let signature = passkey.sign(
+        &env,
+        Bytes::from_array(&env, &[0x05; 37]),
+        Bytes::from_slice(&env, b"{\"type\":\"webauthn.get\"}"),
+    );
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, signature),
+        Err(Ok(AccountError::InvalidClientData))
+    );
+}
+
+#[test]
+fn check_auth_rejects_registration_payload() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let challenge = webauthn::encode_challenge(&payload.to_array());
+    let mut client_data_json =
+        Bytes::from_slice(&env, b"{\"type\":\"webauthn.create\",\"challenge\":\"");
+    client_data_json.extend_from_slice(&challenge);
+    client_data_json.extend_from_slice(b"\",\"origin\":\"https://colibri.test\"}");
+    let signature = passkey.sign(&env, Bytes::from_array(&env, &[0x05; 37]), client_data_json);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, signature),
+        Err(Ok(AccountError::NotAnAssertion))
+    );
+}
+
+#[test]
+fn check_auth_requires_user_presence() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let signature = passkey.assert(&env, &payload, 0x04);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, signature),
+        Err(Ok(AccountError::UserNotPresent))
+    );
+}
+
+#[test]
+fn check_auth_rejects_unknown_credential() {
+    let env = Env::default();
+    let client = create_account(&env, &Passkey::new(&env, 1));
+    let stranger = Passkey::new(&env, 2);
+    let payload = BytesN::random(&env);
+
+    let signature = stranger.assert(&env, &payload, 0x05);
+
+    assert_eq!(
+        check_auth(&env, &client, &payload, signature),
+        Err(Ok(AccountError::UnknownCredential))
+    );
+}
+
+#[test]
+fn check_auth_rejects_signature_from_other_key() {
+    let env = Env::default();
+    let passkey = Passkey::new(&env, 1);
+    let client = create_account(&env, &passkey);
+    let payload = BytesN::random(&env);
+
+    let impostor = Passkey {
+        credential_id: passkey.credential_id.clone(),
+        signing_key: SigningKey::from_slice(&[2u8; 32]).unwrap(),
+    };
+    let signature = impostor.assert(&env, &payload, 0x05);
+
+    assert!(check_auth(&env, &client, &payload, signature).is_err());
+}
+
+#[test]
+fn supports_multiple_credentials() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let laptop = Passkey::new(&env, 1);
+    let phone = Passkey::new(&env, 2);
+    let client = create_account(&env, &laptop);
+
+    client.add_credential(&phone.credential_id, &phone.public_key(&env));
+    assert_eq!(env.auths()[0].0, client.address);
+    assert_eq!(
+        client.credential(&phone.credential_id),
+        Some(phone.public_key(&env))
+    );
+
+    let payload = BytesN::random(&env);
+    assert_eq!(
+        check_auth(&env, &client, &payload, phone.assert(&env, &payload, 0x05)),
+        Ok(())
+    );
+
+    client.remove_credential(&laptop.credential_id);
+    assert_eq!(client.credential(&laptop.credential_id), None);
+    assert_eq!(
+        client.try_remove_credential(&phone.credential_id),
+        Err(Ok(AccountError::LastCredential.into()))
+    );
+}
@@ -0,0 +1,60 @@
+// Minimal WebAuthn helpers, just enough to check an assertion produced by a
+// passkey without pulling a JSON or base64 crate into the contract.
+
+pub const CLIENT_DATA_JSON_MAX_LEN: usize = 1024;
+
+// rpIdHash (32 bytes) + flags (1 byte) + signCount (4 bytes)
+pub const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+
+const FLAGS_INDEX: usize = 32;
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+const CHALLENGE_KEY: &[u8] = b"\"challenge\":\"";
+const TYPE_KEY: &[u8] = b"\"type\":\"";
+const ASSERTION_TYPE: &[u8] = b"webauthn.get";
+
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64url encoding of a 32 byte signature payload, as browsers
+/// place it in the `challenge` field of `clientDataJSON`.
+pub fn encode_challenge(payload: &[u8; 32]) -> [u8; 43] {
+    let mut out = [0u8; 43];
+    let mut chunk = |n: u32, at: usize, chars: usize| {
+        for i in 0..chars {
+            out[at + i] = BASE64_URL_ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize];
+        }
+    };
+
+    for (i, group) in payload.chunks(3).enumerate() {
+        let n = group
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (j, b)| acc | ((*b as u32) << (16 - 8 * j)));
+        chunk(n, i * 4, group.len() + 1);
+    }
+    out
+}
+
+/// Returns the raw value of the `challenge` field in `clientDataJSON`.
+pub fn extract_challenge(client_data_json: &[u8]) -> Option<&[u8]> {
+    string_field(client_data_json, CHALLENGE_KEY)
+}
+
+/// Whether `clientDataJSON` was produced by `navigator.credentials.get`, as
+/// opposed to a `webauthn.create` registration.
+pub fn is_assertion(client_data_json: &[u8]) -> bool {
+    string_field(client_data_json, TYPE_KEY) == Some(ASSERTION_TYPE)
+}
+
+// The raw string following `key`, which includes the opening quote
+fn string_field<'a>(client_data_json: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    let start = client_data_json.windows(key.len()).position(|w| w == key)? + key.len();
+    let len = client_data_json[start..].iter().position(|b| *b == b'"')?;
+    Some(&client_data_json[start..start + len])
+}
+
+pub fn user_present(authenticator_data: &[u8]) -> bool {
+    authenticator_data.len() >= AUTHENTICATOR_DATA_MIN_LEN
+        && authenticator_data[FLAGS_INDEX] & FLAG_USER_PRESENT != 0
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
+    Vec,
+};
+
+#[contract]
+pub struct PrngHarness;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Count,
+    Ticket(u32),
+    Winner,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NoEntries = 1,
+}
+
+// Functions whose result or footprint depends on `env.prng()`. Simulation and
+// execution seed the PRNG differently, so their outcomes are expected to diverge
+#[contractimpl]
+impl PrngHarness {
+    // Stores `player` under its own ticket key; returns the ticket number
+    pub fn enter(env: Env, player: Address) -> u32 {
+        player.require_auth();
+
+        let count: u32 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Ticket(count), &player);
+        env.storage().instance().set(&DataKey::Count, &(count + 1));
+        count
+    }
+
+    // Reads a randomly chosen ticket, so only that ticket's key is in the footprint
+    pub fn draw(env: Env) -> Address {
+        let count: u32 = env.storage().instance().get(&DataKey::Count).unwrap_or(0);
+        if count == 0 {
+            panic_with_error!(&env, Error::NoEntries);
+        }
+
+        let ticket: u64 = env.prng().gen_range(0..count as u64);
+        let winner: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Ticket(ticket as u32))
+            .unwrap();
+        env.storage().instance().set(&DataKey::Winner, &winner);
+        winner
+    }
+
+    // `draw` with the PRNG reseeded first, giving the same winner every time
+    pub fn draw_seeded(env: Env, seed: BytesN<32>) -> Address {
+        env.prng().seed(seed.into());
+        Self::draw(env)
+    }
+
+    pub fn winner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Winner)
+    }
+
+    // Uniform in `0..=max`
+    pub fn roll(env: Env, max: u64) -> u64 {
+        env.prng().gen_range(0..=max)
+    }
+
+    pub fn shuffle(env: Env, v: Vec<u32>) -> Vec<u32> {
+        let mut v = v;
+        env.prng().shuffle(&mut v);
+        v
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::contract::{Error, PrngHarness, PrngHarnessClient};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+
+const PLAYERS: u32 = 10;
+
+// A lottery with `PLAYERS` entries in an env whose base PRNG is seeded with `seed`
+fn lottery(seed: [u8; 32]) -> (Env, PrngHarnessClient<'static>, Vec<Address>) {
+    let env = Env::default();
+    env.host().set_base_prng_seed(seed).unwrap();
+    env.mock_all_auths();
+    let id = env.register(PrngHarness, ());
+    let client = PrngHarnessClient::new(&env, &id);
+
+    let mut players = Vec::new(&env);
+    for _ in 0..PLAYERS {
+        let player = Address::generate(&env);
+        client.enter(&player);
+        players.push_back(player);
+    }
+    (env, client, players)
+}
+
+fn ticket_of(players: &Vec<Address>, winner: &Address) -> u32 {
+    players.first_index_of(winner).unwrap()
+}
+
+#[test]
+fn same_seed_draws_same_ticket() {
+    let (_env, a, players_a) = lottery([1; 32]);
+    let (_env, b, players_b) = lottery([1; 32]);
+
+    let winner_a = a.draw();
+    let winner_b = b.draw();
+
+    assert_eq!(
+        ticket_of(&players_a, &winner_a),
+        ticket_of(&players_b, &winner_b)
+    );
+    assert_eq!(a.winner(), Some(winner_a));
+}
+
+#[test]
+fn different_seeds_draw_different_tickets() {
+    let mut tickets = std::collections::BTreeSet::new();
+    for seed in 0..8u8 {
+        let (_env, client, players) = lottery([seed; 32]);
+        tickets.insert(ticket_of(&players, &client.draw()));
+    }
+
+    assert!(tickets.len() > 1);
+}
+
+#[test]
+fn draw_seeded_ignores_base_seed() {
+    let (env_a, a, players_a) = lottery([1; 32]);
+    let (env_b, b, players_b) = lottery([2; 32]);
+
+    let winner_a = a.draw_seeded(&BytesN::from_array(&env_a, &[9; 32]));
+    let winner_b = b.draw_seeded(&BytesN::from_array(&env_b, &[9; 32]));
+
+    assert_eq!(
+        ticket_of(&players_a, &winner_a),
+        ticket_of(&players_b, &winner_b)
+    );
+}
+
+#[test]
+fn draw_without_entries_fails() {
+    let env = Env::default();
+    let client = PrngHarnessClient::new(&env, &env.register(PrngHarness, ()));
+
+    assert_eq!(client.try_draw(), Err(Ok(Error::NoEntries.into())));
+    assert_eq!(client.winner(), None);
+}
+
+#[test]
+fn roll_is_bounded_and_reproducible() {
+    let (_env, a, _) = lottery([3; 32]);
+    let (_env, b, _) = lottery([3; 32]);
+
+    for max in [0u64, 1, 6, u64::MAX] {
+        let rolled = a.roll(&max);
+        assert!(rolled <= max);
+        assert_eq!(b.roll(&max), rolled);
+    }
+}
+
+#[test]
+fn shuffle_is_a_permutation() {
+    let (env, client, _) = lottery([4; 32]);
+    let v = Vec::from_array(&env, [1u32, 2, 3, 4, 5, 6, 7, 8]);
+
+    let shuffled = client.shuffle(&v);
+    assert_eq!(shuffled.len(), v.len());
+
+    let mut sorted: std::vec::Vec<u32> = shuffled.iter().collect();
+    sorted.sort();
+    assert_eq!(sorted, v.iter().collect::<std::vec::Vec<u32>>());
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{contract, contractimpl, Address, Env, Error, IntoVal, Symbol, Val, Vec};
+
+#[contract]
+pub struct Proxy;
+
+#[contractimpl]
+impl Proxy {
+    // Calls `func` on `target`; failures in the target abort the whole invocation
+    pub fn forward(env: Env, target: Address, func: Symbol, args: Vec<Val>) -> Val {
+        env.invoke_contract(&target, &func, args)
+    }
+
+    // Calls `func` on `target`, capturing the error instead of propagating it. Returns
+    // `(true, result)` on success and `(false, error)` on failure; the error can't be
+    // returned on its own since the host treats a returned `Error` as a failed call.
+    // Not named `try_forward`, which the generated client reserves for `forward`
+    pub fn forward_catch(env: Env, target: Address, func: Symbol, args: Vec<Val>) -> (bool, Val) {
+        match env.try_invoke_contract::<Val, Error>(&target, &func, args) {
+            Ok(Ok(v)) => (true, v),
+            Err(Ok(e)) => (false, e.into_val(&env)),
+            // A conversion failure carries no error value to report
+            Ok(Err(_)) | Err(Err(_)) => (false, Val::VOID.into()),
+        }
+    }
+}
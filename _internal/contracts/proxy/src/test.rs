@@ -0,0 +1,147 @@
+#![cfg(test)]
+use crate::contract::{Proxy, ProxyClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, AuthorizedFunction},
+    vec, Address, Env, Error, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+
+// The harness exports ten-argument functions
+#[allow(clippy::too_many_arguments)]
+mod types_harness {
+    soroban_sdk::contractimport!(file = "../../tests/compiled-contracts/types_harness.wasm");
+}
+
+// The token wasm declares two `Metadata` types, which `contractimport!` cannot
+// express, so its client is declared by hand
+mod fungible_token {
+    use soroban_sdk::{contractclient, Address, Env};
+
+    pub const WASM: &[u8] =
+        include_bytes!("../../../tests/compiled-contracts/fungible_token_contract.wasm");
+
+    #[allow(dead_code)]
+    #[contractclient(name = "Client")]
+    pub trait Token {
+        fn balance(env: Env, account: Address) -> i128;
+    }
+}
+
+fn setup() -> (Env, ProxyClient<'static>, Address) {
+    let env = Env::default();
+    let proxy = env.register(Proxy, ());
+    let harness = env.register(types_harness::WASM, ());
+    (env.clone(), ProxyClient::new(&env, &proxy), harness)
+}
+
+#[test]
+fn forwards_call_and_returns_value() {
+    let (env, proxy, harness) = setup();
+
+    let result = proxy.forward(
+        &harness,
+        &symbol_short!("u32"),
+        &vec![&env, 7u32.into_val(&env)],
+    );
+
+    assert_eq!(u32::try_from_val(&env, &result).unwrap(), 7);
+}
+
+#[test]
+fn forwards_through_nested_proxies() {
+    let (env, outer, harness) = setup();
+    let middle = env.register(Proxy, ());
+    let inner = env.register(Proxy, ());
+
+    let target_args: Vec<Val> = vec![&env, 42u32.into_val(&env)];
+    let inner_args: Vec<Val> = (harness, symbol_short!("u32"), target_args).into_val(&env);
+    let middle_args: Vec<Val> = (inner, Symbol::new(&env, "forward"), inner_args).into_val(&env);
+    let result = outer.forward(&middle, &Symbol::new(&env, "forward"), &middle_args);
+
+    assert_eq!(u32::try_from_val(&env, &result).unwrap(), 42);
+}
+
+#[test]
+fn forward_propagates_target_error() {
+    let (env, proxy, harness) = setup();
+
+    let result = proxy.try_forward(
+        &harness,
+        &symbol_short!("fail"),
+        &vec![&env, true.into_val(&env)],
+    );
+
+    assert_eq!(result.err(), Some(Ok(Error::from_contract_error(123))));
+}
+
+#[test]
+fn forward_catch_captures_target_error() {
+    let (env, proxy, harness) = setup();
+
+    let result = proxy.forward_catch(
+        &harness,
+        &symbol_short!("fail"),
+        &vec![&env, true.into_val(&env)],
+    );
+
+    let (ok, value) = result;
+    assert!(!ok);
+    assert_eq!(
+        Error::try_from_val(&env, &value).unwrap(),
+        Error::from_contract_error(123)
+    );
+}
+
+#[test]
+fn forward_catch_returns_value_on_success() {
+    let (env, proxy, harness) = setup();
+
+    let result = proxy.forward_catch(
+        &harness,
+        &symbol_short!("u32"),
+        &vec![&env, 7u32.into_val(&env)],
+    );
+
+    let (ok, value) = result;
+    assert!(ok);
+    assert_eq!(u32::try_from_val(&env, &value).unwrap(), 7);
+}
+
+#[test]
+fn forwards_token_transfer_with_sender_auth() {
+    let (env, proxy, _) = setup();
+    // The holder authorizes the token call nested under `forward`, not the root call
+    env.mock_all_auths_allowing_non_root_auth();
+    let holder = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let to = Address::generate(&env);
+    let token_id = env.register(
+        fungible_token::WASM,
+        (
+            holder.clone(),
+            owner,
+            String::from_str(&env, "ColibriToken"),
+            String::from_str(&env, "CLBT"),
+            7u32,
+            1_000i128,
+        ),
+    );
+    let token = fungible_token::Client::new(&env, &token_id);
+    let before = token.balance(&holder);
+
+    let args: Vec<Val> = (holder.clone(), to.clone(), 10i128).into_val(&env);
+    proxy.forward(&token_id, &symbol_short!("transfer"), &args);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, holder);
+    match &auths[0].1.function {
+        AuthorizedFunction::Contract((contract, func, _)) => {
+            assert_eq!(contract, &token_id);
+            assert_eq!(func, &symbol_short!("transfer"));
+        }
+        _ => panic!("expected a contract invocation"),
+    }
+    assert_eq!(token.balance(&holder), before - 10);
+    assert_eq!(token.balance(&to), 10);
+}
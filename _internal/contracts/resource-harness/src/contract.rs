@@ -0,0 +1,79 @@
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Bytes, Env};
+
+#[contract]
+pub struct ResourceHarness;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Entry(u32),
+}
+
+#[contractimpl]
+impl ResourceHarness {
+    // CPU: a guest loop whose result depends on every iteration
+    pub fn burn_cpu(_env: Env, iterations: u32) -> u64 {
+        let mut acc = 0u64;
+        for i in 0..iterations {
+            acc = acc.wrapping_mul(6364136223846793005).wrapping_add(i as u64);
+        }
+        acc
+    }
+
+    // Memory: a host `Bytes` object of `bytes` length
+    pub fn alloc(env: Env, bytes: u32) -> u32 {
+        filled_bytes(&env, bytes).len()
+    }
+
+    // Ledger reads: one persistent entry per key in `0..count`, missing or not
+    pub fn read_entries(env: Env, count: u32) -> u32 {
+        let mut found = 0;
+        for i in 0..count {
+            if env.storage().persistent().has(&DataKey::Entry(i)) {
+                found += 1;
+            }
+        }
+        found
+    }
+
+    // Ledger writes: `count` persistent entries of `entry_bytes` each
+    pub fn write_entries(env: Env, count: u32, entry_bytes: u32) {
+        let value = filled_bytes(&env, entry_bytes);
+        for i in 0..count {
+            env.storage().persistent().set(&DataKey::Entry(i), &value);
+        }
+    }
+
+    // Events: a single event carrying `bytes` of data
+    pub fn emit_bytes(env: Env, bytes: u32) {
+        env.events()
+            .publish((symbol_short!("payload"),), filled_bytes(&env, bytes));
+    }
+
+    // All knobs in one invocation
+    pub fn consume(
+        env: Env,
+        cpu_iterations: u32,
+        mem_bytes: u32,
+        read_count: u32,
+        write_count: u32,
+        entry_bytes: u32,
+        event_bytes: u32,
+    ) -> u64 {
+        let acc = Self::burn_cpu(env.clone(), cpu_iterations);
+        Self::alloc(env.clone(), mem_bytes);
+        Self::read_entries(env.clone(), read_count);
+        Self::write_entries(env.clone(), write_count, entry_bytes);
+        Self::emit_bytes(env, event_bytes);
+        acc
+    }
+}
+
+// Grows the buffer by doubling, so large sizes cost few host calls
+fn filled_bytes(env: &Env, len: u32) -> Bytes {
+    let mut bytes = Bytes::from_array(env, &[0xab; 64]);
+    while bytes.len() < len {
+        bytes.append(&bytes.clone());
+    }
+    bytes.slice(..len)
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+use crate::contract::{DataKey, ResourceHarness, ResourceHarnessClient};
+use soroban_sdk::{testutils::Events, Bytes, Env, TryFromVal};
+
+#[test]
+fn burn_cpu_depends_on_iterations() {
+    let env = Env::default();
+    let id = env.register(ResourceHarness, ());
+    let client = ResourceHarnessClient::new(&env, &id);
+
+    assert_eq!(client.burn_cpu(&0), 0);
+    assert_ne!(client.burn_cpu(&1_000), client.burn_cpu(&1_001));
+}
+
+#[test]
+fn alloc_creates_requested_size() {
+    let env = Env::default();
+    let id = env.register(ResourceHarness, ());
+    let client = ResourceHarnessClient::new(&env, &id);
+
+    for bytes in [0, 1, 64, 65, 100_000] {
+        assert_eq!(client.alloc(&bytes), bytes);
+    }
+}
+
+#[test]
+fn writes_then_reads_entries() {
+    let env = Env::default();
+    let id = env.register(ResourceHarness, ());
+    let client = ResourceHarnessClient::new(&env, &id);
+
+    assert_eq!(client.read_entries(&5), 0);
+
+    client.write_entries(&3, &200);
+    assert_eq!(client.read_entries(&5), 3);
+
+    env.as_contract(&id, || {
+        let value: Bytes = env.storage().persistent().get(&DataKey::Entry(2)).unwrap();
+        assert_eq!(value.len(), 200);
+        assert!(!env.storage().persistent().has(&DataKey::Entry(3)));
+    });
+}
+
+#[test]
+fn emits_event_with_requested_data_size() {
+    let env = Env::default();
+    let id = env.register(ResourceHarness, ());
+    let client = ResourceHarnessClient::new(&env, &id);
+
+    client.emit_bytes(&1_000);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(Bytes::try_from_val(&env, &data).unwrap().len(), 1_000);
+}
+
+#[test]
+fn consume_applies_every_knob() {
+    let env = Env::default();
+    let id = env.register(ResourceHarness, ());
+    let client = ResourceHarnessClient::new(&env, &id);
+
+    client.consume(&100, &1_024, &4, &2, &32, &128);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(Bytes::try_from_val(&env, &data).unwrap().len(), 128);
+    assert_eq!(client.read_entries(&4), 2);
+}
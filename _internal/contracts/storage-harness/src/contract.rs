@@ -0,0 +1,147 @@
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Bytes, Env, IntoVal, Map, String,
+    Symbol, Val, Vec, I256, U256,
+};
+
+#[contract]
+pub struct StorageHarness;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageType {
+    Persistent,
+    Temporary,
+    Instance,
+}
+
+// Same shapes as the TypesHarness types, so seeded entries decode with its spec
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+    pub tags: Vec<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NestedType {
+    pub depth: u32,
+    pub width: u32,
+    pub nested: Vec<NestedType>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Choice {
+    None,
+    Count(u32),
+    Label(String),
+}
+
+#[contractimpl]
+impl StorageHarness {
+    // Generic access, keys and values can be any ScVal
+    pub fn put(env: Env, storage: StorageType, key: Val, value: Val) {
+        match storage {
+            StorageType::Persistent => env.storage().persistent().set(&key, &value),
+            StorageType::Temporary => env.storage().temporary().set(&key, &value),
+            StorageType::Instance => env.storage().instance().set(&key, &value),
+        }
+    }
+
+    pub fn get(env: Env, storage: StorageType, key: Val) -> Option<Val> {
+        match storage {
+            StorageType::Persistent => env.storage().persistent().get(&key),
+            StorageType::Temporary => env.storage().temporary().get(&key),
+            StorageType::Instance => env.storage().instance().get(&key),
+        }
+    }
+
+    pub fn has(env: Env, storage: StorageType, key: Val) -> bool {
+        match storage {
+            StorageType::Persistent => env.storage().persistent().has(&key),
+            StorageType::Temporary => env.storage().temporary().has(&key),
+            StorageType::Instance => env.storage().instance().has(&key),
+        }
+    }
+
+    pub fn delete(env: Env, storage: StorageType, key: Val) {
+        match storage {
+            StorageType::Persistent => env.storage().persistent().remove(&key),
+            StorageType::Temporary => env.storage().temporary().remove(&key),
+            StorageType::Instance => env.storage().instance().remove(&key),
+        }
+    }
+
+    // Instance entries have no TTL of their own, so `key` is ignored and the
+    // whole contract instance is extended instead
+    pub fn extend_ttl(env: Env, storage: StorageType, key: Val, threshold: u32, extend_to: u32) {
+        match storage {
+            StorageType::Persistent => env
+                .storage()
+                .persistent()
+                .extend_ttl(&key, threshold, extend_to),
+            StorageType::Temporary => env
+                .storage()
+                .temporary()
+                .extend_ttl(&key, threshold, extend_to),
+            StorageType::Instance => env.storage().instance().extend_ttl(threshold, extend_to),
+        }
+    }
+
+    // Writes one entry per key type so ledger entries can be read back
+    // without building the keys on the caller side
+    pub fn seed(env: Env, storage: StorageType) {
+        let current = env.current_contract_address();
+        let user = User {
+            id: 1,
+            name: String::from_str(&env, "alice"),
+            tags: vec![&env, symbol_short!("admin")],
+        };
+        let nested = NestedType {
+            depth: 1,
+            width: 1,
+            nested: vec![
+                &env,
+                NestedType {
+                    depth: 0,
+                    width: 0,
+                    nested: vec![&env],
+                },
+            ],
+        };
+        let samples: [(Val, Val); 9] = [
+            (symbol_short!("counter").into_val(&env), 1u32.into_val(&env)),
+            (
+                7u32.into_val(&env),
+                String::from_str(&env, "seven").into_val(&env),
+            ),
+            (current.into_val(&env), i128::MAX.into_val(&env)),
+            (
+                Bytes::from_array(&env, &[0xde, 0xad]).into_val(&env),
+                true.into_val(&env),
+            ),
+            (
+                Vec::from_array(&env, [symbol_short!("a"), symbol_short!("b")]).into_val(&env),
+                Map::from_array(&env, [(symbol_short!("k"), 1u64)]).into_val(&env),
+            ),
+            (
+                (symbol_short!("pair"), current.clone()).into_val(&env),
+                Vec::from_array(&env, [current]).into_val(&env),
+            ),
+            (user.into_val(&env), Choice::Count(3).into_val(&env)),
+            (
+                Choice::Label(String::from_str(&env, "tree")).into_val(&env),
+                nested.into_val(&env),
+            ),
+            (
+                U256::from_u128(&env, u128::MAX).into_val(&env),
+                I256::from_i128(&env, i128::MIN).into_val(&env),
+            ),
+        ];
+        for (key, value) in samples {
+            Self::put(env.clone(), storage, key, value);
+        }
+    }
+}
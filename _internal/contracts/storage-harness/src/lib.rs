@@ -0,0 +1,3 @@
+#![no_std]
+mod contract;
+mod test;
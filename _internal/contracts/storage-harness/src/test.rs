@@ -0,0 +1,149 @@
+#![cfg(test)]
+use crate::contract::{
+    Choice, NestedType, StorageHarness, StorageHarnessClient, StorageType, User,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        storage::{Instance as _, Persistent as _, Temporary as _},
+        Address as _,
+    },
+    vec, Address, Env, IntoVal, String, TryFromVal, Val, I256, U256,
+};
+
+const ALL_STORAGE: [StorageType; 3] = [
+    StorageType::Persistent,
+    StorageType::Temporary,
+    StorageType::Instance,
+];
+
+#[test]
+fn put_get_delete_roundtrip() {
+    let env = Env::default();
+    let id = env.register(StorageHarness, ());
+    let client = StorageHarnessClient::new(&env, &id);
+
+    let key: Val = Address::generate(&env).into_val(&env);
+    let value: Val = String::from_str(&env, "stored").into_val(&env);
+
+    for storage in ALL_STORAGE {
+        assert!(!client.has(&storage, &key));
+        assert!(client.get(&storage, &key).is_none());
+
+        client.put(&storage, &key, &value);
+        assert!(client.has(&storage, &key));
+        let out = client.get(&storage, &key).unwrap();
+        assert_eq!(
+            String::try_from_val(&env, &out).unwrap(),
+            String::from_str(&env, "stored")
+        );
+
+        client.delete(&storage, &key);
+        assert!(!client.has(&storage, &key));
+    }
+}
+
+#[test]
+fn storage_types_are_isolated() {
+    let env = Env::default();
+    let id = env.register(StorageHarness, ());
+    let client = StorageHarnessClient::new(&env, &id);
+
+    let key: Val = symbol_short!("shared").into_val(&env);
+    client.put(&StorageType::Persistent, &key, &1u32.into_val(&env));
+
+    assert!(client.has(&StorageType::Persistent, &key));
+    assert!(!client.has(&StorageType::Temporary, &key));
+    assert!(!client.has(&StorageType::Instance, &key));
+}
+
+#[test]
+fn extend_ttl_per_storage_type() {
+    let env = Env::default();
+    let id = env.register(StorageHarness, ());
+    let client = StorageHarnessClient::new(&env, &id);
+
+    let key: Val = symbol_short!("ttl").into_val(&env);
+    let value: Val = 1u32.into_val(&env);
+    client.put(&StorageType::Persistent, &key, &value);
+    client.put(&StorageType::Temporary, &key, &value);
+    client.put(&StorageType::Instance, &key, &value);
+
+    client.extend_ttl(&StorageType::Persistent, &key, &5_000, &5_000);
+    client.extend_ttl(&StorageType::Temporary, &key, &3_000, &3_000);
+    client.extend_ttl(&StorageType::Instance, &key, &6_000, &6_000);
+
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().persistent().get_ttl(&key), 5_000);
+        assert_eq!(env.storage().temporary().get_ttl(&key), 3_000);
+        assert_eq!(env.storage().instance().get_ttl(), 6_000);
+    });
+}
+
+#[test]
+fn seed_writes_one_entry_per_key_type() {
+    let env = Env::default();
+    let id = env.register(StorageHarness, ());
+    let client = StorageHarnessClient::new(&env, &id);
+
+    for storage in ALL_STORAGE {
+        client.seed(&storage);
+    }
+
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().persistent().all().len(), 9);
+        assert_eq!(env.storage().temporary().all().len(), 9);
+        assert_eq!(env.storage().instance().all().len(), 9);
+    });
+
+    let counter = client
+        .get(
+            &StorageType::Persistent,
+            &symbol_short!("counter").into_val(&env),
+        )
+        .unwrap();
+    assert_eq!(u32::try_from_val(&env, &counter).unwrap(), 1);
+    let seven = client
+        .get(&StorageType::Temporary, &7u32.into_val(&env))
+        .unwrap();
+    assert_eq!(
+        String::try_from_val(&env, &seven).unwrap(),
+        String::from_str(&env, "seven")
+    );
+
+    let user = User {
+        id: 1,
+        name: String::from_str(&env, "alice"),
+        tags: vec![&env, symbol_short!("admin")],
+    };
+    let choice = client
+        .get(&StorageType::Persistent, &user.into_val(&env))
+        .unwrap();
+    assert_eq!(
+        Choice::try_from_val(&env, &choice).unwrap(),
+        Choice::Count(3)
+    );
+    let nested = client
+        .get(
+            &StorageType::Instance,
+            &Choice::Label(String::from_str(&env, "tree")).into_val(&env),
+        )
+        .unwrap();
+    assert_eq!(
+        NestedType::try_from_val(&env, &nested)
+            .unwrap()
+            .nested
+            .len(),
+        1
+    );
+    let signed = client
+        .get(
+            &StorageType::Temporary,
+            &U256::from_u128(&env, u128::MAX).into_val(&env),
+        )
+        .unwrap();
+    assert_eq!(
+        I256::try_from_val(&env, &signed).unwrap(),
+        I256::from_i128(&env, i128::MIN)
+    );
+}
@@ -0,0 +1,122 @@
+use core::str;
+use soroban_sdk::{contract, contracterror, contractimpl, Bytes, Env, String, Symbol};
+
+// Strings are copied out of the host to be inspected, so their length is capped
+pub const MAX_STRING_LEN: usize = 256;
+pub const MAX_SYMBOL_LEN: usize = 32;
+
+#[contract]
+pub struct StringsHarness;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    OutOfRange = 1,
+    NotCharBoundary = 2,
+    InvalidUtf8 = 3,
+    TooLong = 4,
+    SymbolTooLong = 5,
+    InvalidSymbolChar = 6,
+}
+
+#[contractimpl]
+impl StringsHarness {
+    // Bytes
+    pub fn bytes_len(_env: Env, b: Bytes) -> u32 {
+        b.len()
+    }
+    // Bytes `start..end`
+    pub fn bytes_slice(_env: Env, b: Bytes, start: u32, end: u32) -> Result<Bytes, Error> {
+        if start > end || end > b.len() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(b.slice(start..end))
+    }
+    pub fn bytes_concat(_env: Env, a: Bytes, b: Bytes) -> Bytes {
+        let mut out = a;
+        out.append(&b);
+        out
+    }
+
+    // String; lengths and offsets count bytes, not characters
+    pub fn string_len(_env: Env, s: String) -> u32 {
+        s.len()
+    }
+    // Number of UTF-8 characters
+    pub fn string_char_count(_env: Env, s: String) -> Result<u32, Error> {
+        let mut buf = [0u8; MAX_STRING_LEN];
+        let text = as_str(copy_string(&s, &mut buf)?)?;
+        Ok(text.chars().count() as u32)
+    }
+    // String bytes `start..end`, which must fall on character boundaries
+    pub fn string_slice(env: Env, s: String, start: u32, end: u32) -> Result<String, Error> {
+        let mut buf = [0u8; MAX_STRING_LEN];
+        let bytes = copy_string(&s, &mut buf)?;
+        let (start, end) = (start as usize, end as usize);
+        if start > end || end > bytes.len() {
+            return Err(Error::OutOfRange);
+        }
+        let text = as_str(bytes)?;
+        if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+            return Err(Error::NotCharBoundary);
+        }
+        Ok(String::from_str(&env, &text[start..end]))
+    }
+    pub fn string_concat(env: Env, a: String, b: String) -> Result<String, Error> {
+        let (a_len, b_len) = (a.len() as usize, b.len() as usize);
+        if a_len + b_len > MAX_STRING_LEN {
+            return Err(Error::TooLong);
+        }
+        let mut buf = [0u8; MAX_STRING_LEN];
+        a.copy_into_slice(&mut buf[..a_len]);
+        b.copy_into_slice(&mut buf[a_len..a_len + b_len]);
+        Ok(String::from_bytes(&env, &buf[..a_len + b_len]))
+    }
+    pub fn string_to_bytes(env: Env, s: String) -> Result<Bytes, Error> {
+        let mut buf = [0u8; MAX_STRING_LEN];
+        Ok(Bytes::from_slice(&env, copy_string(&s, &mut buf)?))
+    }
+    // Not validated: the host accepts any bytes as a String, including invalid UTF-8
+    pub fn string_from_bytes(env: Env, b: Bytes) -> Result<String, Error> {
+        let len = b.len() as usize;
+        if len > MAX_STRING_LEN {
+            return Err(Error::TooLong);
+        }
+        let mut buf = [0u8; MAX_STRING_LEN];
+        b.copy_into_slice(&mut buf[..len]);
+        Ok(String::from_bytes(&env, &buf[..len]))
+    }
+
+    // Symbol
+    pub fn symbol_from_string(env: Env, s: String) -> Result<Symbol, Error> {
+        if s.len() as usize > MAX_SYMBOL_LEN {
+            return Err(Error::SymbolTooLong);
+        }
+        let mut buf = [0u8; MAX_SYMBOL_LEN];
+        let bytes = copy_string(&s, &mut buf)?;
+        if !bytes
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() || *c == b'_')
+        {
+            return Err(Error::InvalidSymbolChar);
+        }
+        Ok(Symbol::new(&env, as_str(bytes)?))
+    }
+    pub fn symbol_eq(_env: Env, a: Symbol, b: Symbol) -> bool {
+        a == b
+    }
+}
+
+fn copy_string<'a>(s: &String, buf: &'a mut [u8]) -> Result<&'a [u8], Error> {
+    let len = s.len() as usize;
+    if len > buf.len() {
+        return Err(Error::TooLong);
+    }
+    s.copy_into_slice(&mut buf[..len]);
+    Ok(&buf[..len])
+}
+
+fn as_str(bytes: &[u8]) -> Result<&str, Error> {
+    str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+use crate::contract::{Error, StringsHarness, StringsHarnessClient, MAX_STRING_LEN};
+use soroban_sdk::{symbol_short, Bytes, Env, String, Symbol};
+
+const SYMBOL_32: &str = "abcdefghijklmnopqrstuvwxyz_01234";
+
+fn setup() -> (Env, StringsHarnessClient<'static>) {
+    let env = Env::default();
+    let id = env.register(StringsHarness, ());
+    let client = StringsHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+#[test]
+fn bytes_slice_and_concat() {
+    let (env, client) = setup();
+    let b = Bytes::from_slice(&env, b"colibri");
+
+    assert_eq!(client.bytes_len(&b), 7);
+    assert_eq!(client.bytes_len(&Bytes::new(&env)), 0);
+    assert_eq!(
+        client.bytes_slice(&b, &1, &4),
+        Bytes::from_slice(&env, b"oli")
+    );
+    assert_eq!(client.bytes_slice(&b, &7, &7), Bytes::new(&env));
+    assert_eq!(
+        client.try_bytes_slice(&b, &0, &8),
+        Err(Ok(Error::OutOfRange))
+    );
+    assert_eq!(
+        client.try_bytes_slice(&b, &4, &1),
+        Err(Ok(Error::OutOfRange))
+    );
+
+    assert_eq!(
+        client.bytes_concat(&b, &Bytes::from_array(&env, &[0, 0xff])),
+        Bytes::from_slice(&env, b"colibri\x00\xff")
+    );
+    assert_eq!(client.bytes_concat(&Bytes::new(&env), &b), b);
+}
+
+#[test]
+fn string_lengths_count_bytes_and_chars() {
+    let (env, client) = setup();
+
+    for (text, bytes, chars) in [
+        ("", 0, 0),
+        ("hello", 5, 5),
+        ("héllo", 6, 5),
+        ("日本語", 9, 3),
+        ("🦀", 4, 1),
+    ] {
+        let s = String::from_str(&env, text);
+        assert_eq!(client.string_len(&s), bytes);
+        assert_eq!(client.string_char_count(&s), chars);
+    }
+}
+
+#[test]
+fn string_slice_respects_char_boundaries() {
+    let (env, client) = setup();
+    let s = String::from_str(&env, "日本語");
+
+    assert_eq!(
+        client.string_slice(&s, &3, &6),
+        String::from_str(&env, "本")
+    );
+    assert_eq!(client.string_slice(&s, &0, &9), s);
+    assert_eq!(
+        client.try_string_slice(&s, &1, &3),
+        Err(Ok(Error::NotCharBoundary))
+    );
+    assert_eq!(
+        client.try_string_slice(&s, &0, &10),
+        Err(Ok(Error::OutOfRange))
+    );
+}
+
+#[test]
+fn string_concat_and_bytes() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.string_concat(
+            &String::from_str(&env, "héllo "),
+            &String::from_str(&env, "🦀")
+        ),
+        String::from_str(&env, "héllo 🦀")
+    );
+    assert_eq!(
+        client.string_to_bytes(&String::from_str(&env, "é")),
+        Bytes::from_array(&env, &[0xc3, 0xa9])
+    );
+
+    let half = String::from_bytes(&env, &[b'a'; MAX_STRING_LEN / 2]);
+    assert_eq!(
+        client.string_concat(&half, &half).len() as usize,
+        MAX_STRING_LEN
+    );
+    assert_eq!(
+        client.try_string_concat(
+            &half,
+            &String::from_bytes(&env, &[b'a'; MAX_STRING_LEN / 2 + 1])
+        ),
+        Err(Ok(Error::TooLong))
+    );
+}
+
+#[test]
+fn invalid_utf8_strings() {
+    let (env, client) = setup();
+
+    // A lone continuation byte and a truncated multi-byte sequence
+    for bytes in [&[0x80u8][..], &[0xe6, 0x97][..]] {
+        let s = client.string_from_bytes(&Bytes::from_slice(&env, bytes));
+        assert_eq!(client.string_len(&s), bytes.len() as u32);
+        assert_eq!(
+            client.try_string_char_count(&s),
+            Err(Ok(Error::InvalidUtf8))
+        );
+        assert_eq!(client.string_to_bytes(&s), Bytes::from_slice(&env, bytes));
+    }
+}
+
+#[test]
+fn symbol_from_string_limits() {
+    let (env, client) = setup();
+
+    assert_eq!(
+        client.symbol_from_string(&String::from_str(&env, "short")),
+        symbol_short!("short")
+    );
+    assert_eq!(
+        client.symbol_from_string(&String::from_str(&env, "ten_chars_")),
+        Symbol::new(&env, "ten_chars_")
+    );
+    assert_eq!(
+        client.symbol_from_string(&String::from_str(&env, SYMBOL_32)),
+        Symbol::new(&env, SYMBOL_32)
+    );
+    assert_eq!(
+        client.try_symbol_from_string(&String::from_str(&env, "abcdefghijklmnopqrstuvwxyz_012345")),
+        Err(Ok(Error::SymbolTooLong))
+    );
+
+    for invalid in ["with space", "dash-ed", "héllo", "dot."] {
+        assert_eq!(
+            client.try_symbol_from_string(&String::from_str(&env, invalid)),
+            Err(Ok(Error::InvalidSymbolChar))
+        );
+    }
+}
+
+#[test]
+fn symbols_compare_by_value() {
+    let (env, client) = setup();
+
+    assert!(client.symbol_eq(&symbol_short!("abc"), &Symbol::new(&env, "abc")));
+    assert!(client.symbol_eq(&Symbol::new(&env, SYMBOL_32), &Symbol::new(&env, SYMBOL_32)));
+    assert!(!client.symbol_eq(&symbol_short!("abc"), &symbol_short!("ABC")));
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, Env, IntoVal,
+    Symbol, Val,
+};
+
+#[contract]
+pub struct TemporaryHarness;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TempKey {
+    Nonce(u64),
+    Lock(Symbol),
+    Session(Address),
+}
+
+// Persistent record of every temporary entry ever written, so an entry that
+// was evicted can be told apart from one that never existed. The TTL is the
+// one requested, before the network minimum is applied
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Issued {
+    pub ledger: u32,
+    pub requested_ttl: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EntryStatus {
+    NeverExisted,
+    Live,
+    Expired(Issued),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub(crate) enum DataKey {
+    Issued(TempKey),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    InvalidTtl = 1,
+    NonceAlreadyUsed = 2,
+    LockHeld = 3,
+}
+
+// TTLs below the network minimum for temporary entries are raised to it
+#[contractimpl]
+impl TemporaryHarness {
+    pub fn use_nonce(env: Env, nonce: u64, ttl: u32) {
+        let key = TempKey::Nonce(nonce);
+        if env.storage().temporary().has(&key) {
+            panic_with_error!(&env, Error::NonceAlreadyUsed);
+        }
+        write_temp(&env, &key, &true, ttl);
+    }
+
+    pub fn acquire_lock(env: Env, resource: Symbol, owner: Address, ttl: u32) {
+        owner.require_auth();
+
+        let key = TempKey::Lock(resource);
+        let holder: Option<Address> = env.storage().temporary().get(&key);
+        if holder.is_some_and(|holder| holder != owner) {
+            panic_with_error!(&env, Error::LockHeld);
+        }
+        write_temp(&env, &key, &owner, ttl);
+    }
+
+    pub fn lock_owner(env: Env, resource: Symbol) -> Option<Address> {
+        env.storage().temporary().get(&TempKey::Lock(resource))
+    }
+
+    pub fn start_session(env: Env, user: Address, ttl: u32) {
+        user.require_auth();
+        write_temp(&env, &TempKey::Session(user), &env.ledger().sequence(), ttl);
+    }
+
+    // Ledger sequence the session was started at, while it is live
+    pub fn session(env: Env, user: Address) -> Option<u32> {
+        env.storage().temporary().get(&TempKey::Session(user))
+    }
+
+    pub fn status(env: Env, key: TempKey) -> EntryStatus {
+        if env.storage().temporary().has(&key) {
+            return EntryStatus::Live;
+        }
+        match env.storage().persistent().get(&DataKey::Issued(key)) {
+            Some(issued) => EntryStatus::Expired(issued),
+            None => EntryStatus::NeverExisted,
+        }
+    }
+}
+
+fn write_temp<V>(env: &Env, key: &TempKey, value: &V, ttl: u32)
+where
+    V: IntoVal<Env, Val>,
+{
+    if ttl == 0 {
+        panic_with_error!(env, Error::InvalidTtl);
+    }
+
+    env.storage().temporary().set(key, value);
+    env.storage().temporary().extend_ttl(key, ttl, ttl);
+
+    let issued_key = DataKey::Issued(key.clone());
+    env.storage().persistent().set(
+        &issued_key,
+        &Issued {
+            ledger: env.ledger().sequence(),
+            requested_ttl: ttl,
+        },
+    );
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(&issued_key, max_ttl, max_ttl);
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+use crate::contract::{
+    DataKey, EntryStatus, Error, Issued, TempKey, TemporaryHarness, TemporaryHarnessClient,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger as _},
+    Address, Env,
+};
+
+const MIN_TEMP_TTL: u32 = 10;
+
+fn setup() -> (Env, TemporaryHarnessClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_min_temp_entry_ttl(MIN_TEMP_TTL);
+    let id = env.register(TemporaryHarness, ());
+    let client = TemporaryHarnessClient::new(&env, &id);
+    (env, client)
+}
+
+#[test]
+fn nonce_expires_and_is_reported_as_expired() {
+    let (env, client) = setup();
+    let key = TempKey::Nonce(1);
+
+    assert_eq!(client.status(&key), EntryStatus::NeverExisted);
+
+    client.use_nonce(&1, &50);
+    assert_eq!(client.status(&key), EntryStatus::Live);
+    assert_eq!(
+        client.try_use_nonce(&1, &50),
+        Err(Ok(Error::NonceAlreadyUsed.into()))
+    );
+
+    env.ledger().set_sequence_number(50);
+    assert_eq!(client.status(&key), EntryStatus::Live);
+
+    env.ledger().set_sequence_number(51);
+    assert_eq!(
+        client.status(&key),
+        EntryStatus::Expired(Issued {
+            ledger: 0,
+            requested_ttl: 50
+        })
+    );
+    assert_eq!(client.status(&TempKey::Nonce(2)), EntryStatus::NeverExisted);
+}
+
+#[test]
+fn evicted_nonce_can_be_written_again() {
+    let (env, client) = setup();
+
+    client.use_nonce(&1, &20);
+    env.ledger().set_sequence_number(21);
+    client.use_nonce(&1, &20);
+
+    assert_eq!(client.status(&TempKey::Nonce(1)), EntryStatus::Live);
+}
+
+#[test]
+fn short_ttl_is_raised_to_network_minimum() {
+    let (env, client) = setup();
+
+    client.use_nonce(&1, &1);
+
+    env.ledger().set_sequence_number(MIN_TEMP_TTL - 1);
+    assert_eq!(client.status(&TempKey::Nonce(1)), EntryStatus::Live);
+    env.ledger().set_sequence_number(MIN_TEMP_TTL);
+    assert_eq!(
+        client.status(&TempKey::Nonce(1)),
+        EntryStatus::Expired(Issued {
+            ledger: 0,
+            requested_ttl: 1
+        })
+    );
+}
+
+#[test]
+fn issued_record_is_extended_on_write() {
+    let (env, client) = setup();
+
+    client.use_nonce(&1, &20);
+
+    env.as_contract(&client.address, || {
+        let key = DataKey::Issued(TempKey::Nonce(1));
+        assert_eq!(
+            env.storage().persistent().get_ttl(&key),
+            env.storage().max_ttl()
+        );
+    });
+}
+
+#[test]
+fn zero_ttl_is_rejected() {
+    let (_env, client) = setup();
+
+    assert_eq!(
+        client.try_use_nonce(&1, &0),
+        Err(Ok(Error::InvalidTtl.into()))
+    );
+}
+
+#[test]
+fn lock_is_released_on_expiry() {
+    let (env, client) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let resource = symbol_short!("vault");
+
+    client.acquire_lock(&resource, &alice, &20);
+    assert_eq!(client.lock_owner(&resource), Some(alice.clone()));
+    assert_eq!(
+        client.try_acquire_lock(&resource, &bob, &20),
+        Err(Ok(Error::LockHeld.into()))
+    );
+
+    env.ledger().set_sequence_number(21);
+    assert_eq!(client.lock_owner(&resource), None);
+    client.acquire_lock(&resource, &bob, &20);
+    assert_eq!(client.lock_owner(&resource), Some(bob));
+}
+
+#[test]
+fn session_expires() {
+    let (env, client) = setup();
+    let user = Address::generate(&env);
+
+    env.ledger().set_sequence_number(5);
+    client.start_session(&user, &30);
+    assert_eq!(client.session(&user), Some(5));
+
+    env.ledger().set_sequence_number(35);
+    assert_eq!(client.session(&user), Some(5));
+
+    env.ledger().set_sequence_number(36);
+    assert_eq!(client.session(&user), None);
+    assert_eq!(
+        client.status(&TempKey::Session(user)),
+        EntryStatus::Expired(Issued {
+            ledger: 5,
+            requested_ttl: 30
+        })
+    );
+}
@@ -1,6 +1,7 @@
 use soroban_sdk::{
-    assert_with_error, contract, contracterror, contractimpl, contracttype, Address, Bytes,
-    Duration, Env, Map, String, Symbol, Timepoint, Val, Vec, I256, U256,
+    assert_with_error, contract, contracterror, contractimpl, contracttype, panic_with_error,
+    symbol_short, Address, Bytes, BytesN, ConversionError, Duration, Env, Map, String, Symbol,
+    Timepoint, TryFromVal, Val, Vec, I256, U256,
 };
 
 #[contract]
@@ -26,6 +27,13 @@ pub struct NestedType {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
+    InvalidArgument = 1,
+    NotFound = 2,
+    Unauthorized = 3,
+    LimitExceeded = 4,
+    InvalidState = 5,
+    // Raised for codes that match no other variant
+    UnknownCode = 6,
     FailedWithCustomError = 123,
 }
 
@@ -37,6 +45,58 @@ pub enum Choice {
     Label(String),
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Priority {
+    Low = 1,
+    Medium = 5,
+    High = 10,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pair(pub Symbol, pub i128);
+
+// Echoes of the wide-argument functions, one field per parameter
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WideArgs {
+    pub id: u32,
+    pub amount: i128,
+    pub limit: Option<u32>,
+    pub tag: Symbol,
+    pub spender: Option<Address>,
+    pub data: Bytes,
+    pub enabled: bool,
+    pub memo: Option<String>,
+    pub user: User,
+    pub choice: Choice,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WideNumbers {
+    pub small: u32,
+    pub signed: i32,
+    pub big: u64,
+    pub offset: Option<i64>,
+    pub supply: u128,
+    pub balance: i128,
+    pub cap: Option<U256>,
+    pub debt: I256,
+    pub priority: Priority,
+    pub pair: Pair,
+}
+
 #[contractimpl]
 impl TypesHarness {
     // Unit
@@ -152,10 +212,292 @@ impl TypesHarness {
         flat_vec
     }
 
+    // Fixed-size bytes
+    pub fn bytes_n32(_env: Env, v: BytesN<32>) -> BytesN<32> {
+        v
+    }
+    pub fn bytes_n64(_env: Env, v: BytesN<64>) -> BytesN<64> {
+        v
+    }
+
+    // Tuples
+    pub fn tuple(_env: Env, v: (u32, Symbol, Address)) -> (u32, Symbol, Address) {
+        v
+    }
+    pub fn tuple_nested(_env: Env, v: (User, (i128, Vec<u32>))) -> (User, (i128, Vec<u32>)) {
+        v
+    }
+    pub fn swap(_env: Env, v: (u32, String)) -> (String, u32) {
+        (v.1, v.0)
+    }
+
+    // Integer enum, unit-only enum and tuple struct
+    pub fn priority(_env: Env, v: Priority) -> Priority {
+        v
+    }
+    pub fn level(_env: Env, v: Level) -> Level {
+        v
+    }
+    pub fn pair(_env: Env, v: Pair) -> Pair {
+        v
+    }
+
+    // Map keys beyond Symbol and Address
+    pub fn map_u32_i128(_env: Env, m: Map<u32, i128>) -> Map<u32, i128> {
+        m
+    }
+    pub fn map_i128_u32(_env: Env, m: Map<i128, u32>) -> Map<i128, u32> {
+        m
+    }
+    pub fn map_addr_u32(_env: Env, m: Map<Address, u32>) -> Map<Address, u32> {
+        m
+    }
+    pub fn map_bytes_u32(_env: Env, m: Map<Bytes, u32>) -> Map<Bytes, u32> {
+        m
+    }
+    pub fn map_tuple_u32(_env: Env, m: Map<(u32, Symbol), u32>) -> Map<(u32, Symbol), u32> {
+        m
+    }
+    pub fn map_user_u32(_env: Env, m: Map<User, u32>) -> Map<User, u32> {
+        m
+    }
+
+    // Maps built in the contract, with keys inserted out of order; values are
+    // the insertion index so callers can check the host re-sorted the keys
+    pub fn build_map_u32(env: Env) -> Map<u32, i128> {
+        let mut m = Map::new(&env);
+        for (i, k) in [30u32, 1, u32::MAX, 0, 20].into_iter().enumerate() {
+            m.set(k, i as i128);
+        }
+        m
+    }
+    pub fn build_map_i128(env: Env) -> Map<i128, u32> {
+        let mut m = Map::new(&env);
+        for (i, k) in [5i128, -7, 0, i128::MIN, i128::MAX, -1]
+            .into_iter()
+            .enumerate()
+        {
+            m.set(k, i as u32);
+        }
+        m
+    }
+    pub fn build_map_addr(env: Env, keys: Vec<Address>) -> Map<Address, u32> {
+        let mut m = Map::new(&env);
+        for (i, k) in keys.iter().enumerate() {
+            m.set(k, i as u32);
+        }
+        m
+    }
+    pub fn build_map_bytes(env: Env) -> Map<Bytes, u32> {
+        let keys = [
+            Bytes::from_array(&env, &[2]),
+            Bytes::from_array(&env, &[1, 0xff]),
+            Bytes::new(&env),
+            Bytes::from_array(&env, &[1]),
+            Bytes::from_array(&env, &[0, 0, 0]),
+        ];
+        let mut m = Map::new(&env);
+        for (i, k) in keys.into_iter().enumerate() {
+            m.set(k, i as u32);
+        }
+        m
+    }
+    pub fn build_map_tuple(env: Env) -> Map<(u32, Symbol), u32> {
+        let keys = [
+            (2u32, symbol_short!("a")),
+            (1, symbol_short!("b")),
+            (1, symbol_short!("a")),
+            (0, symbol_short!("z")),
+        ];
+        let mut m = Map::new(&env);
+        for (i, k) in keys.into_iter().enumerate() {
+            m.set(k, i as u32);
+        }
+        m
+    }
+    pub fn build_map_user(env: Env) -> Map<User, u32> {
+        let mut m = Map::new(&env);
+        for (i, id) in [3u32, 1, 2].into_iter().enumerate() {
+            let user = User {
+                id,
+                name: String::from_str(&env, "user"),
+                tags: Vec::new(&env),
+            };
+            m.set(user, i as u32);
+        }
+        m
+    }
+
+    // Events
+    pub fn emit(env: Env, topics: Vec<Val>, data: Val) {
+        env.events().publish(topics, data);
+    }
+
+    // Publishes an event with `count` topics (0 to 4) and the count as data
+    pub fn emit_topics(env: Env, count: u32) {
+        assert_with_error!(env, count <= 4, Error::InvalidArgument);
+        let mut topics: Vec<Symbol> = Vec::new(&env);
+        for name in [
+            symbol_short!("t0"),
+            symbol_short!("t1"),
+            symbol_short!("t2"),
+            symbol_short!("t3"),
+        ]
+        .into_iter()
+        .take(count as usize)
+        {
+            topics.push_back(name);
+        }
+        env.events().publish(topics, count);
+    }
+    pub fn emit_user(env: Env, u: User) {
+        env.events().publish((symbol_short!("user"), u.id), u);
+    }
+    pub fn emit_choice(env: Env, c: Choice) {
+        env.events().publish((symbol_short!("choice"),), c);
+    }
+    pub fn emit_nested_type(env: Env, v: NestedType) {
+        env.events()
+            .publish((symbol_short!("nested"), v.depth, v.width), v);
+    }
+    pub fn emit_map(env: Env, m: Map<Symbol, Val>) {
+        env.events().publish((symbol_short!("map"),), m);
+    }
+    pub fn emit_vec(env: Env, v: Vec<Val>) {
+        env.events().publish((symbol_short!("vec"),), v);
+    }
+    pub fn emit_big_ints(env: Env, a: u128, b: i128, c: U256, d: I256) {
+        env.events().publish((symbol_short!("u128"),), a);
+        env.events().publish((symbol_short!("i128"),), b);
+        env.events().publish((symbol_short!("u256"),), c);
+        env.events().publish((symbol_short!("i256"),), d);
+    }
+
     // Error handling
     pub fn fail(env: Env, should_fail: bool) {
         assert_with_error!(env, !should_fail, Error::FailedWithCustomError);
     }
+
+    // Raises the `Error` variant whose code matches `code`, or `UnknownCode`
+    pub fn fail_with_error(env: Env, code: u32) {
+        panic_with_error!(&env, error_for_code(code));
+    }
+
+    // Host failure classes, each triggered on purpose
+    pub fn fail_panic(_env: Env) {
+        panic!("explicit panic");
+    }
+    pub fn fail_overflow(_env: Env, a: u32, b: u32) -> u32 {
+        a + b
+    }
+    pub fn fail_missing_storage(env: Env) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&symbol_short!("missing"), 1, 1);
+    }
+    pub fn fail_auth(_env: Env, addr: Address) {
+        addr.require_auth();
+    }
+    pub fn fail_budget(env: Env, iterations: u32) {
+        let data = Bytes::from_array(&env, &[0u8; 1024]);
+        for _ in 0..iterations {
+            env.crypto().sha256(&data);
+        }
+    }
+    pub fn fail_index_out_of_bounds(_env: Env, v: Vec<u32>, index: u32) -> u32 {
+        v.get_unchecked(index)
+    }
+    pub fn fail_invalid_conversion(env: Env, v: Val) -> u32 {
+        u32::try_from_val(&env, &v).unwrap_or_else(|e: ConversionError| panic_with_error!(&env, e))
+    }
+
+    // Result outputs: `code` 0 returns the value, any other code the matching
+    // `Error` variant as a spec-declared error instead of a panic
+    pub fn result_u32(_env: Env, v: u32, code: u32) -> Result<u32, Error> {
+        match code {
+            0 => Ok(v),
+            _ => Err(error_for_code(code)),
+        }
+    }
+    pub fn result_user(_env: Env, u: User, code: u32) -> Result<User, Error> {
+        match code {
+            0 => Ok(u),
+            _ => Err(error_for_code(code)),
+        }
+    }
+    pub fn result_void(_env: Env, code: u32) -> Result<(), Error> {
+        match code {
+            0 => Ok(()),
+            _ => Err(error_for_code(code)),
+        }
+    }
+
+    // Wide argument lists, at the spec limit of 10 inputs. Parameter names are
+    // not in alphabetical order, unlike the fields of the returned structs
+    pub fn wide(
+        _env: Env,
+        id: u32,
+        amount: i128,
+        limit: Option<u32>,
+        tag: Symbol,
+        spender: Option<Address>,
+        data: Bytes,
+        enabled: bool,
+        memo: Option<String>,
+        user: User,
+        choice: Choice,
+    ) -> WideArgs {
+        WideArgs {
+            id,
+            amount,
+            limit,
+            tag,
+            spender,
+            data,
+            enabled,
+            memo,
+            user,
+            choice,
+        }
+    }
+    pub fn wide_numbers(
+        _env: Env,
+        small: u32,
+        signed: i32,
+        big: u64,
+        offset: Option<i64>,
+        supply: u128,
+        balance: i128,
+        cap: Option<U256>,
+        debt: I256,
+        priority: Priority,
+        pair: Pair,
+    ) -> WideNumbers {
+        WideNumbers {
+            small,
+            signed,
+            big,
+            offset,
+            supply,
+            balance,
+            cap,
+            debt,
+            priority,
+            pair,
+        }
+    }
+}
+
+fn error_for_code(code: u32) -> Error {
+    match code {
+        1 => Error::InvalidArgument,
+        2 => Error::NotFound,
+        3 => Error::Unauthorized,
+        4 => Error::LimitExceeded,
+        5 => Error::InvalidState,
+        123 => Error::FailedWithCustomError,
+        _ => Error::UnknownCode,
+    }
 }
 
 fn flatten_helper(env: &Env, v: &NestedType, acc: &mut Vec<NestedType>) {
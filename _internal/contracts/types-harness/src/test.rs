@@ -1,8 +1,15 @@
 #![cfg(test)]
-use crate::contract::{Choice, TypesHarness, TypesHarnessClient, User};
+extern crate std;
+use crate::contract::{
+    Choice, Error, Level, NestedType, Pair, Priority, TypesHarness, TypesHarnessClient, User,
+    WideArgs, WideNumbers,
+};
 use soroban_sdk::{
-    symbol_short, testutils::Address as _, Address, Bytes, Duration, Env, IntoVal, Map, String,
-    Symbol, Timepoint, TryFromVal, Val, Vec, I256, U256,
+    symbol_short,
+    testutils::{Address as _, Events},
+    xdr::{ContractEventBody, ScError, ScErrorCode, ScErrorType, ScVal},
+    Address, Bytes, BytesN, Duration, Env, IntoVal, Map, String, Symbol, Timepoint, TryFromVal,
+    Val, Vec, I256, U256,
 };
 
 #[test]
@@ -114,3 +121,529 @@ fn roundtrip_udts() {
     mu.set(addr, user.clone());
     assert_eq!(client.map_addr_user(&mu), mu);
 }
+
+#[test]
+fn roundtrip_bytes_n() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let b32 = BytesN::from_array(&env, &[0xab; 32]);
+    let b64 = BytesN::from_array(&env, &[0xcd; 64]);
+    assert_eq!(client.bytes_n32(&b32), b32);
+    assert_eq!(client.bytes_n64(&b64), b64);
+}
+
+#[test]
+#[should_panic(expected = "Error(WasmVm, InvalidAction)")]
+fn bytes_n_rejects_wrong_length() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+
+    let args: Vec<Val> = (Bytes::from_array(&env, &[0u8; 31]),).into_val(&env);
+    let _: BytesN<32> = env.invoke_contract(&id, &Symbol::new(&env, "bytes_n32"), args);
+}
+
+#[test]
+fn roundtrip_tuples() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let addr = Address::generate(&env);
+    let t = (7u32, symbol_short!("seven"), addr);
+    assert_eq!(client.tuple(&t), t);
+
+    let user = User {
+        id: 2,
+        name: String::from_str(&env, "Lifo"),
+        tags: Vec::new(&env),
+    };
+    let nested = (user, (-5i128, Vec::from_array(&env, [1u32, 2, 3])));
+    assert_eq!(client.tuple_nested(&nested), nested);
+
+    let s = String::from_str(&env, "x");
+    assert_eq!(client.swap(&(1u32, s.clone())), (s, 1u32));
+}
+
+#[test]
+fn roundtrip_enums_and_tuple_struct() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    for p in [Priority::Low, Priority::Medium, Priority::High] {
+        assert_eq!(client.priority(&p), p);
+    }
+    for l in [Level::Debug, Level::Info, Level::Warn] {
+        assert_eq!(client.level(&l), l);
+    }
+    let pair = Pair(symbol_short!("k"), -1);
+    assert_eq!(client.pair(&pair), pair);
+
+    // Integer enums travel as their discriminant, unit-only enums as a one-symbol vec
+    let p: Val = Priority::Medium.into_val(&env);
+    assert_eq!(u32::try_from_val(&env, &p).unwrap(), 5);
+    let l: Val = Level::Info.into_val(&env);
+    let l = Vec::<Symbol>::try_from_val(&env, &l).unwrap();
+    assert_eq!(l, Vec::from_array(&env, [symbol_short!("Info")]));
+}
+
+#[test]
+#[should_panic(expected = "Error(WasmVm, InvalidAction)")]
+fn priority_rejects_unknown_discriminant() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+
+    let args: Vec<Val> = (2u32,).into_val(&env);
+    let _: Priority = env.invoke_contract(&id, &Symbol::new(&env, "priority"), args);
+}
+
+#[test]
+fn roundtrip_map_keys() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let m = Map::from_array(&env, [(2u32, -2i128), (1, -1)]);
+    assert_eq!(client.map_u32_i128(&m), m);
+
+    let m = Map::from_array(&env, [(-2i128, 2u32), (i128::MAX, 1)]);
+    assert_eq!(client.map_i128_u32(&m), m);
+
+    let m = Map::from_array(
+        &env,
+        [
+            (Address::generate(&env), 1u32),
+            (Address::generate(&env), 2),
+        ],
+    );
+    assert_eq!(client.map_addr_u32(&m), m);
+
+    let m = Map::from_array(
+        &env,
+        [
+            (Bytes::from_array(&env, &[9, 9]), 1u32),
+            (Bytes::new(&env), 2),
+        ],
+    );
+    assert_eq!(client.map_bytes_u32(&m), m);
+
+    let m = Map::from_array(
+        &env,
+        [
+            ((1u32, symbol_short!("b")), 1u32),
+            ((1, symbol_short!("a")), 2),
+        ],
+    );
+    assert_eq!(client.map_tuple_u32(&m), m);
+
+    let user = User {
+        id: 1,
+        name: String::from_str(&env, "Fifo"),
+        tags: Vec::from_array(&env, [symbol_short!("dev")]),
+    };
+    let m = Map::from_array(&env, [(user, 1u32)]);
+    assert_eq!(client.map_user_u32(&m), m);
+}
+
+#[test]
+fn built_maps_are_key_ordered() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let m = client.build_map_u32();
+    assert_eq!(m.keys(), Vec::from_array(&env, [0u32, 1, 20, 30, u32::MAX]));
+    assert_eq!(m.values(), Vec::from_array(&env, [3i128, 1, 4, 0, 2]));
+
+    let m = client.build_map_i128();
+    assert_eq!(
+        m.keys(),
+        Vec::from_array(&env, [i128::MIN, -7, -1, 0, 5, i128::MAX])
+    );
+
+    let addrs = Vec::from_array(
+        &env,
+        [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ],
+    );
+    let keys = client.build_map_addr(&addrs).keys();
+    assert_eq!(keys.len(), 3);
+    for i in 1..keys.len() {
+        assert!(keys.get(i - 1).unwrap() < keys.get(i).unwrap());
+    }
+
+    let m = client.build_map_bytes();
+    assert_eq!(
+        m.keys(),
+        Vec::from_array(
+            &env,
+            [
+                Bytes::new(&env),
+                Bytes::from_array(&env, &[0, 0, 0]),
+                Bytes::from_array(&env, &[1]),
+                Bytes::from_array(&env, &[1, 0xff]),
+                Bytes::from_array(&env, &[2]),
+            ]
+        )
+    );
+
+    let m = client.build_map_tuple();
+    assert_eq!(
+        m.keys(),
+        Vec::from_array(
+            &env,
+            [
+                (0u32, symbol_short!("z")),
+                (1, symbol_short!("a")),
+                (1, symbol_short!("b")),
+                (2, symbol_short!("a")),
+            ]
+        )
+    );
+
+    let mut ids: Vec<u32> = Vec::new(&env);
+    for user in client.build_map_user().keys().iter() {
+        ids.push_back(user.id);
+    }
+    assert_eq!(ids, Vec::from_array(&env, [1u32, 2, 3]));
+}
+
+#[test]
+fn error_matrix_contract_errors() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    for error in [
+        Error::InvalidArgument,
+        Error::NotFound,
+        Error::Unauthorized,
+        Error::LimitExceeded,
+        Error::InvalidState,
+        Error::UnknownCode,
+        Error::FailedWithCustomError,
+    ] {
+        let err = client
+            .try_fail_with_error(&(error as u32))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, error.into());
+    }
+
+    let err = client.try_fail_with_error(&999).unwrap_err().unwrap();
+    assert_eq!(err, Error::UnknownCode.into());
+}
+
+#[test]
+fn result_outputs() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let user = User {
+        id: 1,
+        name: String::from_str(&env, "Fifo"),
+        tags: Vec::new(&env),
+    };
+    assert_eq!(client.result_u32(&7, &0), 7);
+    assert_eq!(client.result_user(&user, &0), user);
+    assert_eq!(client.try_result_void(&0), Ok(Ok(())));
+
+    for error in [
+        Error::InvalidArgument,
+        Error::NotFound,
+        Error::LimitExceeded,
+    ] {
+        let code = error as u32;
+        assert_eq!(client.try_result_u32(&7, &code), Err(Ok(error)));
+        assert_eq!(client.try_result_user(&user, &code), Err(Ok(error)));
+        assert_eq!(client.try_result_void(&code), Err(Ok(error)));
+    }
+
+    // Codes outside `Error` map to `UnknownCode`
+    assert_eq!(client.try_result_void(&999), Err(Ok(Error::UnknownCode)));
+}
+
+// Calls from the test see host errors narrowed to `Error(Context, InvalidAction)`;
+// the error the host raised first is only recorded in the diagnostic events
+fn raised_error(call: impl FnOnce(&Env, &TypesHarnessClient)) -> ScError {
+    let env = Env::default();
+    let client = TypesHarnessClient::new(&env, &env.register(TypesHarness, ()));
+    call(&env, &client);
+
+    let events = env.host().get_diagnostic_events().unwrap();
+    events
+        .0
+        .iter()
+        .find_map(|e| {
+            let ContractEventBody::V0(body) = &e.event.body;
+            match (body.topics.first(), body.topics.get(1)) {
+                (Some(ScVal::Symbol(topic)), Some(ScVal::Error(err)))
+                    if topic.0.as_slice() == b"error" =>
+                {
+                    Some(err.clone())
+                }
+                _ => None,
+            }
+        })
+        .expect("no error diagnostic event")
+}
+
+#[test]
+fn error_matrix_host_errors() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    assert_eq!(client.try_fail_overflow(&1, &2), Ok(Ok(3)));
+    let v = Vec::from_array(&env, [1u32, 2, 3]);
+    assert_eq!(client.try_fail_index_out_of_bounds(&v, &2), Ok(Ok(3)));
+    let ok: Val = 7u32.into_val(&env);
+    assert_eq!(client.try_fail_invalid_conversion(&ok), Ok(Ok(7)));
+
+    // Every host error reaches the caller as the same narrowed error
+    assert_eq!(
+        client.try_fail_missing_storage(),
+        Err(Ok(soroban_sdk::Error::from_type_and_code(
+            ScErrorType::Context,
+            ScErrorCode::InvalidAction
+        )))
+    );
+
+    assert_eq!(
+        raised_error(|_, c| assert!(c.try_fail_panic().is_err())),
+        ScError::WasmVm(ScErrorCode::InvalidAction)
+    );
+    assert_eq!(
+        raised_error(|_, c| assert!(c.try_fail_overflow(&u32::MAX, &1).is_err())),
+        ScError::WasmVm(ScErrorCode::InvalidAction)
+    );
+    assert_eq!(
+        raised_error(|_, c| assert!(c.try_fail_missing_storage().is_err())),
+        ScError::Storage(ScErrorCode::MissingValue)
+    );
+    assert_eq!(
+        raised_error(|env, c| assert!(c.try_fail_auth(&Address::generate(env)).is_err())),
+        ScError::Auth(ScErrorCode::InvalidAction)
+    );
+    assert_eq!(
+        raised_error(|env, c| {
+            let v = Vec::from_array(env, [1u32, 2, 3]);
+            assert!(c.try_fail_index_out_of_bounds(&v, &3).is_err());
+        }),
+        ScError::Object(ScErrorCode::IndexBounds)
+    );
+    // The contract raises the SDK's `ConversionError`, which maps to this code
+    assert_eq!(
+        raised_error(|env, c| {
+            let not_u32: Val = symbol_short!("nope").into_val(env);
+            assert!(c.try_fail_invalid_conversion(&not_u32).is_err());
+        }),
+        ScError::Context(ScErrorCode::UnexpectedType)
+    );
+}
+
+#[test]
+fn emits_events_with_chosen_topic_counts() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    for count in 0..=4u32 {
+        client.emit_topics(&count);
+        let (contract, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(contract, id);
+        assert_eq!(topics.len(), count);
+        assert_eq!(u32::try_from_val(&env, &data).unwrap(), count);
+    }
+
+    assert!(client.try_emit_topics(&5).is_err());
+
+    let topics: Vec<Val> = Vec::from_array(
+        &env,
+        [symbol_short!("custom").into_val(&env), 42u32.into_val(&env)],
+    );
+    let data: Val = String::from_str(&env, "payload").into_val(&env);
+    client.emit(&topics, &data);
+    let (_, out_topics, out_data) = env.events().all().last().unwrap();
+    assert_eq!(out_topics, topics);
+    assert_eq!(
+        String::try_from_val(&env, &out_data).unwrap(),
+        String::from_str(&env, "payload")
+    );
+}
+
+#[test]
+fn emits_events_with_udt_data() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let user = User {
+        id: 7,
+        name: String::from_str(&env, "Fifo"),
+        tags: Vec::from_array(&env, [symbol_short!("dev")]),
+    };
+    client.emit_user(&user);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (symbol_short!("user"), 7u32).into_val(&env);
+    assert_eq!(topics, expected);
+    assert_eq!(User::try_from_val(&env, &data).unwrap(), user);
+
+    let choice = Choice::Label(String::from_str(&env, "ok"));
+    client.emit_choice(&choice);
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(Choice::try_from_val(&env, &data).unwrap(), choice);
+
+    let nested = NestedType {
+        depth: 2,
+        width: 1,
+        nested: Vec::from_array(
+            &env,
+            [NestedType {
+                depth: 1,
+                width: 0,
+                nested: Vec::new(&env),
+            }],
+        ),
+    };
+    client.emit_nested_type(&nested);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics.len(), 3);
+    assert_eq!(NestedType::try_from_val(&env, &data).unwrap(), nested);
+}
+
+#[test]
+fn emits_events_with_container_and_big_int_data() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let mut m: Map<Symbol, Val> = Map::new(&env);
+    m.set(symbol_short!("k"), 1i128.into_val(&env));
+    client.emit_map(&m);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let out: Map<Symbol, Val> = Map::try_from_val(&env, &data).unwrap();
+    assert_eq!(out.len(), 1);
+
+    let v: Vec<Val> = Vec::from_array(&env, [true.into_val(&env), 3u64.into_val(&env)]);
+    client.emit_vec(&v);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let out: Vec<Val> = Vec::try_from_val(&env, &data).unwrap();
+    assert_eq!(out.len(), 2);
+
+    let u256 = U256::from_u128(&env, u128::MAX).shl(8);
+    let i256 = I256::from_i128(&env, i128::MIN).shl(8);
+    client.emit_big_ints(&u128::MAX, &i128::MIN, &u256, &i256);
+    let events = env.events().all();
+    let n = events.len();
+    let (_, _, data) = events.get(n - 2).unwrap();
+    assert_eq!(U256::try_from_val(&env, &data).unwrap(), u256);
+    let (_, _, data) = events.get(n - 1).unwrap();
+    assert_eq!(I256::try_from_val(&env, &data).unwrap(), i256);
+}
+
+#[test]
+fn wide_arguments_echo_in_order() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let user = User {
+        id: 9,
+        name: String::from_str(&env, "Fifo"),
+        tags: Vec::from_array(&env, [symbol_short!("dev")]),
+    };
+    let spender = Address::generate(&env);
+    let data = Bytes::from_array(&env, &[1, 2, 3]);
+    let memo = String::from_str(&env, "memo");
+    let choice = Choice::Count(4);
+
+    assert_eq!(
+        client.wide(
+            &1,
+            &-2,
+            &Some(3),
+            &symbol_short!("tag"),
+            &Some(spender.clone()),
+            &data,
+            &true,
+            &Some(memo.clone()),
+            &user,
+            &choice,
+        ),
+        WideArgs {
+            id: 1,
+            amount: -2,
+            limit: Some(3),
+            tag: symbol_short!("tag"),
+            spender: Some(spender),
+            data: data.clone(),
+            enabled: true,
+            memo: Some(memo),
+            user: user.clone(),
+            choice: choice.clone(),
+        }
+    );
+
+    // Options in the middle left empty
+    let echo = client.wide(
+        &1,
+        &-2,
+        &None,
+        &symbol_short!("tag"),
+        &None,
+        &data,
+        &false,
+        &None,
+        &user,
+        &choice,
+    );
+    assert_eq!(echo.limit, None);
+    assert_eq!(echo.spender, None);
+    assert_eq!(echo.memo, None);
+    assert_eq!(echo.user, user);
+}
+
+#[test]
+fn wide_numbers_echo_in_order() {
+    let env = Env::default();
+    let id = env.register(TypesHarness, ());
+    let client = TypesHarnessClient::new(&env, &id);
+
+    let cap = U256::from_u128(&env, u128::MAX);
+    let debt = I256::from_i128(&env, i128::MIN);
+    let pair = Pair(symbol_short!("k"), 7);
+
+    assert_eq!(
+        client.wide_numbers(
+            &u32::MAX,
+            &i32::MIN,
+            &u64::MAX,
+            &Some(i64::MIN),
+            &u128::MAX,
+            &i128::MIN,
+            &Some(cap.clone()),
+            &debt,
+            &Priority::High,
+            &pair,
+        ),
+        WideNumbers {
+            small: u32::MAX,
+            signed: i32::MIN,
+            big: u64::MAX,
+            offset: Some(i64::MIN),
+            supply: u128::MAX,
+            balance: i128::MIN,
+            cap: Some(cap),
+            debt,
+            priority: Priority::High,
+            pair,
+        }
+    );
+}
@@ -0,0 +1,24 @@
+// deno-coverage-ignore-file
+
+import { Spec } from "stellar-sdk/contract";
+export const ERRORS_HARNESS_SPEC = new Spec([
+  "AAAAAAAAAAAAAAALcGF1c2VfZXJyb3IAAAAAAQAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApQYXVzZUVycm9yAAA=",
+  "AAAAAAAAAAAAAAALdG9rZW5fZXJyb3IAAAAAAQAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApUb2tlbkVycm9yAAA=",
+  "AAAAAAAAAAAAAAALdmF1bHRfZXJyb3IAAAAAAQAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApWYXVsdEVycm9yAAA=",
+  "AAAAAAAAAAAAAAAMYWNjZXNzX2Vycm9yAAAAAQAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAAAtBY2Nlc3NFcnJvcgA=",
+  "AAAABAAAAAAAAAAAAAAAClBhdXNlRXJyb3IAAAAAAAIAAAAAAAAADUVuZm9yY2VkUGF1c2UAAAAAAAPoAAAAAAAAAA1FeHBlY3RlZFBhdXNlAAAAAAAD6Q==",
+  "AAAABAAAAAAAAAAAAAAAClRva2VuRXJyb3IAAAAAAAMAAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAAQAAAAAAAAAVSW5zdWZmaWNpZW50QWxsb3dhbmNlAAAAAAAAAgAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAM=",
+  "AAAABAAAAAAAAAAAAAAAClZhdWx0RXJyb3IAAAAAAAMAAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAAQAAAAAAAAAGTG9ja2VkAAAAAAADAAAAAAAAAAZQYXVzZWQAAAAAA+g=",
+  "AAAABAAAAAAAAAAAAAAAC0FjY2Vzc0Vycm9yAAAAAAMAAAAAAAAADFVuYXV0aG9yaXplZAAAAAEAAAAAAAAADlJvbGVOb3RHcmFudGVkAAAAAAACAAAAAAAAAAtPd25lck5vdFNldAAAAAAD",
+  "AAAAAAAAAAAAAAAScGFuaWNfdmF1bHRfbG9ja2VkAAAAAAAAAAAAAA==",
+  "AAAAAAAAAAAAAAAUcGFuaWNfZW5mb3JjZWRfcGF1c2UAAAAAAAAAAA==",
+]);
+
+export enum ERRORS_HARNESS_METHOD {
+  PAUSE_ERROR = "pause_error",
+  TOKEN_ERROR = "token_error",
+  VAULT_ERROR = "vault_error",
+  ACCESS_ERROR = "access_error",
+  PANIC_VAULT_LOCKED = "panic_vault_locked",
+  PANIC_ENFORCED_PAUSE = "panic_enforced_pause",
+}
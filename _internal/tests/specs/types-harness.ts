@@ -2,77 +2,164 @@
 
 import { Spec } from "stellar-sdk/contract";
 export const TYPES_HARNESS_SPEC = new Spec([
-  "AAAAAQAAAAAAAAAAAAAABFVzZXIAAAADAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEQ==",
-  "AAAAAQAAAAAAAAAAAAAACk5lc3RlZFR5cGUAAAAAAAMAAAAAAAAABWRlcHRoAAAAAAAABAAAAAAAAAAGbmVzdGVkAAAAAAPqAAAH0AAAAApOZXN0ZWRUeXBlAAAAAAAAAAAABXdpZHRoAAAAAAAABA==",
-  "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAAQAAAAAAAAAVRmFpbGVkV2l0aEN1c3RvbUVycm9yAAAAAAAAew==",
-  "AAAAAgAAAAAAAAAAAAAABkNob2ljZQAAAAAAAwAAAAAAAAAAAAAABE5vbmUAAAABAAAAAAAAAAVDb3VudAAAAAAAAAEAAAAEAAAAAQAAAAAAAAAFTGFiZWwAAAAAAAABAAAAEA==",
-  "AAAAAAAAAAAAAAAEdm9pZAAAAAAAAAAA",
-  "AAAAAAAAAAAAAAAEYm9vbAAAAAEAAAAAAAAAAXYAAAAAAAABAAAAAQAAAAE=",
-  "AAAAAAAAAAAAAAADdTMyAAAAAAEAAAAAAAAAAXYAAAAAAAAEAAAAAQAAAAQ=",
+  "AAAAAAAAAAAAAAADYW55AAAAAAEAAAAAAAAAAXYAAAAAAAAAAAAAAQAAAAA=",
   "AAAAAAAAAAAAAAADaTMyAAAAAAEAAAAAAAAAAXYAAAAAAAAFAAAAAQAAAAU=",
-  "AAAAAAAAAAAAAAADdTY0AAAAAAEAAAAAAAAAAXYAAAAAAAAGAAAAAQAAAAY=",
   "AAAAAAAAAAAAAAADaTY0AAAAAAEAAAAAAAAAAXYAAAAAAAAHAAAAAQAAAAc=",
-  "AAAAAAAAAAAAAAAJdGltZXBvaW50AAAAAAAAAQAAAAAAAAABdgAAAAAAAAgAAAABAAAACA==",
-  "AAAAAAAAAAAAAAAIZHVyYXRpb24AAAABAAAAAAAAAAF2AAAAAAAACQAAAAEAAAAJ",
-  "AAAAAAAAAAAAAAAEdTEyOAAAAAEAAAAAAAAAAXYAAAAAAAAKAAAAAQAAAAo=",
+  "AAAAAAAAAAAAAAADdTMyAAAAAAEAAAAAAAAAAXYAAAAAAAAEAAAAAQAAAAQ=",
+  "AAAAAAAAAAAAAAADdTY0AAAAAAEAAAAAAAAAAXYAAAAAAAAGAAAAAQAAAAY=",
+  "AAAAAAAAAAAAAAAEYm9vbAAAAAEAAAAAAAAAAXYAAAAAAAABAAAAAQAAAAE=",
+  "AAAAAAAAAAAAAAAEZW1pdAAAAAIAAAAAAAAABnRvcGljcwAAAAAD6gAAAAAAAAAAAAAABGRhdGEAAAAAAAAAAA==",
+  "AAAAAAAAAAAAAAAEZmFpbAAAAAEAAAAAAAAAC3Nob3VsZF9mYWlsAAAAAAEAAAAA",
   "AAAAAAAAAAAAAAAEaTEyOAAAAAEAAAAAAAAAAXYAAAAAAAALAAAAAQAAAAs=",
-  "AAAAAAAAAAAAAAAEdTI1NgAAAAEAAAAAAAAAAXYAAAAAAAAMAAAAAQAAAAw=",
   "AAAAAAAAAAAAAAAEaTI1NgAAAAEAAAAAAAAAAXYAAAAAAAANAAAAAQAAAA0=",
+  "AAAAAAAAAAAAAAAEcGFpcgAAAAEAAAAAAAAAAXYAAAAAAAfQAAAABFBhaXIAAAABAAAH0AAAAARQYWly",
+  "AAAAAAAAAAAAAAAEc3dhcAAAAAEAAAAAAAAAAXYAAAAAAAPtAAAAAgAAAAQAAAAQAAAAAQAAA+0AAAACAAAAEAAAAAQ=",
+  "AAAAAAAAAAAAAAAEdTEyOAAAAAEAAAAAAAAAAXYAAAAAAAAKAAAAAQAAAAo=",
+  "AAAAAAAAAAAAAAAEdTI1NgAAAAEAAAAAAAAAAXYAAAAAAAAMAAAAAQAAAAw=",
+  "AAAAAAAAAAAAAAAEdXNlcgAAAAEAAAAAAAAAAXUAAAAAAAfQAAAABFVzZXIAAAABAAAH0AAAAARVc2Vy",
+  "AAAAAAAAAAAAAAAEdm9pZAAAAAAAAAAA",
+  "AAAAAAAAAAAAAAAEd2lkZQAAAAoAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABWxpbWl0AAAAAAAD6AAAAAQAAAAAAAAAA3RhZwAAAAARAAAAAAAAAAdzcGVuZGVyAAAAA+gAAAATAAAAAAAAAARkYXRhAAAADgAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAABHVzZXIAAAfQAAAABFVzZXIAAAAAAAAABmNob2ljZQAAAAAH0AAAAAZDaG9pY2UAAAAAAAEAAAfQAAAACFdpZGVBcmdz",
   "AAAAAAAAAAAAAAAFYnl0ZXMAAAAAAAABAAAAAAAAAAF2AAAAAAAADgAAAAEAAAAO",
+  "AAAAAAAAAAAAAAAFbGV2ZWwAAAAAAAABAAAAAAAAAAF2AAAAAAAH0AAAAAVMZXZlbAAAAAAAAAEAAAfQAAAABUxldmVsAAAA",
+  "AAAAAAAAAAAAAAAFdHVwbGUAAAAAAAABAAAAAAAAAAF2AAAAAAAD7QAAAAMAAAAEAAAAEQAAABMAAAABAAAD7QAAAAMAAAAEAAAAEQAAABM=",
+  "AAAAAAAAAAAAAAAGY2hvaWNlAAAAAAABAAAAAAAAAAFjAAAAAAAH0AAAAAZDaG9pY2UAAAAAAAEAAAfQAAAABkNob2ljZQAA",
   "AAAAAAAAAAAAAAAGc3RyaW5nAAAAAAABAAAAAAAAAAF2AAAAAAAAEAAAAAEAAAAQ",
   "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAABAAAAAAAAAAF2AAAAAAAAEQAAAAEAAAAR",
+  "AAAAAQAAAAAAAAAAAAAABFBhaXIAAAACAAAAAAAAAAEwAAAAAAAAEQAAAAAAAAABMQAAAAAAAAs=",
+  "AAAAAQAAAAAAAAAAAAAABFVzZXIAAAADAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEQ==",
   "AAAAAAAAAAAAAAAHYWRkcmVzcwAAAAABAAAAAAAAAAF2AAAAAAAAEwAAAAEAAAAT",
+  "AAAAAAAAAAAAAAAHdmVjX2FueQAAAAABAAAAAAAAAAF2AAAAAAAD6gAAAAAAAAABAAAD6gAAAAA=",
+  "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABwAAAAAAAAAPSW52YWxpZEFyZ3VtZW50AAAAAAEAAAAAAAAACE5vdEZvdW5kAAAAAgAAAAAAAAAMVW5hdXRob3JpemVkAAAAAwAAAAAAAAANTGltaXRFeGNlZWRlZAAAAAAAAAQAAAAAAAAADEludmFsaWRTdGF0ZQAAAAUAAAAAAAAAC1Vua25vd25Db2RlAAAAAAYAAAAAAAAAFUZhaWxlZFdpdGhDdXN0b21FcnJvcgAAAAAAAHs=",
+  "AAAAAgAAAAAAAAAAAAAABUxldmVsAAAAAAAAAwAAAAAAAAAAAAAABURlYnVnAAAAAAAAAAAAAAAAAAAESW5mbwAAAAAAAAAAAAAABFdhcm4=",
+  "AAAAAAAAAAAAAAAIZHVyYXRpb24AAAABAAAAAAAAAAF2AAAAAAAACQAAAAEAAAAJ",
+  "AAAAAAAAAAAAAAAIZW1pdF9tYXAAAAABAAAAAAAAAAFtAAAAAAAD7AAAABEAAAAAAAAAAA==",
+  "AAAAAAAAAAAAAAAIZW1pdF92ZWMAAAABAAAAAAAAAAF2AAAAAAAD6gAAAAAAAAAA",
+  "AAAAAAAAAAAAAAAIcHJpb3JpdHkAAAABAAAAAAAAAAF2AAAAAAAH0AAAAAhQcmlvcml0eQAAAAEAAAfQAAAACFByaW9yaXR5",
   "AAAAAAAAAAAAAAAIdmVjX2kxMjgAAAABAAAAAAAAAAF2AAAAAAAD6gAAAAsAAAABAAAD6gAAAAs=",
+  "AAAAAAAAAAAAAAAIdmVjX3VzZXIAAAABAAAAAAAAAAF2AAAAAAAD6gAAB9AAAAAEVXNlcgAAAAEAAAPqAAAH0AAAAARVc2Vy",
+  "AAAAAgAAAAAAAAAAAAAABkNob2ljZQAAAAAAAwAAAAAAAAAAAAAABE5vbmUAAAABAAAAAAAAAAVDb3VudAAAAAAAAAEAAAAEAAAAAQAAAAAAAAAFTGFiZWwAAAAAAAABAAAAEA==",
+  "AAAAAAAAAAAAAAAJYnl0ZXNfbjMyAAAAAAAAAQAAAAAAAAABdgAAAAAAA+4AAAAgAAAAAQAAA+4AAAAg",
+  "AAAAAAAAAAAAAAAJYnl0ZXNfbjY0AAAAAAAAAQAAAAAAAAABdgAAAAAAA+4AAABAAAAAAQAAA+4AAABA",
+  "AAAAAAAAAAAAAAAJZW1pdF91c2VyAAAAAAAAAQAAAAAAAAABdQAAAAAAB9AAAAAEVXNlcgAAAAA=",
+  "AAAAAAAAAAAAAAAJZmFpbF9hdXRoAAAAAAAAAQAAAAAAAAAEYWRkcgAAABMAAAAA",
+  "AAAAAAAAAAAAAAAJdGltZXBvaW50AAAAAAAAAQAAAAAAAAABdgAAAAAAAAgAAAABAAAACA==",
+  "AAAAAAAAAAAAAAAKZmFpbF9wYW5pYwAAAAAAAAAAAAA=",
+  "AAAAAAAAAAAAAAAKb3B0aW9uX3UzMgAAAAAAAQAAAAAAAAABdgAAAAAAA+gAAAAEAAAAAQAAA+gAAAAE",
+  "AAAAAAAAAAAAAAAKcmVzdWx0X3UzMgAAAAAAAgAAAAAAAAABdgAAAAAAAAQAAAAAAAAABGNvZGUAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+  "AAAAAwAAAAAAAAAAAAAACFByaW9yaXR5AAAAAwAAAAAAAAADTG93AAAAAAEAAAAAAAAABk1lZGl1bQAAAAAABQAAAAAAAAAESGlnaAAAAAo=",
+  "AAAAAQAAAAAAAAAAAAAACFdpZGVBcmdzAAAACgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAZjaG9pY2UAAAAAB9AAAAAGQ2hvaWNlAAAAAAAAAAAABGRhdGEAAAAOAAAAAAAAAAdlbmFibGVkAAAAAAEAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAA+gAAAAEAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAAB3NwZW5kZXIAAAAD6AAAABMAAAAAAAAAA3RhZwAAAAARAAAAAAAAAAR1c2VyAAAH0AAAAARVc2Vy",
+  "AAAAAAAAAAAAAAALZW1pdF9jaG9pY2UAAAAAAQAAAAAAAAABYwAAAAAAB9AAAAAGQ2hvaWNlAAAAAAAA",
+  "AAAAAAAAAAAAAAALZW1pdF90b3BpY3MAAAAAAQAAAAAAAAAFY291bnQAAAAAAAAEAAAAAA==",
+  "AAAAAAAAAAAAAAALZmFpbF9idWRnZXQAAAAAAQAAAAAAAAAKaXRlcmF0aW9ucwAAAAAABAAAAAA=",
+  "AAAAAAAAAAAAAAALbWFwX3N5bV9hbnkAAAAAAQAAAAAAAAABbQAAAAAAA+wAAAARAAAAAAAAAAEAAAPsAAAAEQAAAAA=",
+  "AAAAAAAAAAAAAAALbmVzdGVkX3R5cGUAAAAAAQAAAAAAAAABdgAAAAAAB9AAAAAKTmVzdGVkVHlwZQAAAAAAAQAAB9AAAAAKTmVzdGVkVHlwZQAA",
+  "AAAAAAAAAAAAAAALb3B0aW9uX3VzZXIAAAAAAQAAAAAAAAABdgAAAAAAA+gAAAfQAAAABFVzZXIAAAABAAAD6AAAB9AAAAAEVXNlcg==",
+  "AAAAAAAAAAAAAAALcmVzdWx0X3VzZXIAAAAAAgAAAAAAAAABdQAAAAAAB9AAAAAEVXNlcgAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAB9AAAAAEVXNlcgAAAAM=",
+  "AAAAAAAAAAAAAAALcmVzdWx0X3ZvaWQAAAAAAQAAAAAAAAAEY29kZQAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
   "AAAAAAAAAAAAAAALdmVjX2FkZHJlc3MAAAAAAQAAAAAAAAABdgAAAAAAA+oAAAATAAAAAQAAA+oAAAAT",
+  "AAAAAAAAAAAAAAAMbWFwX2FkZHJfdTMyAAAAAQAAAAAAAAABbQAAAAAAA+wAAAATAAAABAAAAAEAAAPsAAAAEwAAAAQ=",
+  "AAAAAAAAAAAAAAAMbWFwX2kxMjhfdTMyAAAAAQAAAAAAAAABbQAAAAAAA+wAAAALAAAABAAAAAEAAAPsAAAACwAAAAQ=",
   "AAAAAAAAAAAAAAAMbWFwX3N5bV9pMTI4AAAAAQAAAAAAAAABbQAAAAAAA+wAAAARAAAACwAAAAEAAAPsAAAAEQAAAAs=",
-  "AAAAAAAAAAAAAAAQbWFwX3N5bV92ZWNfYWRkcgAAAAEAAAAAAAAAAW0AAAAAAAPsAAAAEQAAA+oAAAATAAAAAQAAA+wAAAARAAAD6gAAABM=",
-  "AAAAAAAAAAAAAAADYW55AAAAAAEAAAAAAAAAAXYAAAAAAAAAAAAAAQAAAAA=",
-  "AAAAAAAAAAAAAAAHdmVjX2FueQAAAAABAAAAAAAAAAF2AAAAAAAD6gAAAAAAAAABAAAD6gAAAAA=",
-  "AAAAAAAAAAAAAAALbWFwX3N5bV9hbnkAAAAAAQAAAAAAAAABbQAAAAAAA+wAAAARAAAAAAAAAAEAAAPsAAAAEQAAAAA=",
-  "AAAAAAAAAAAAAAAEdXNlcgAAAAEAAAAAAAAAAXUAAAAAAAfQAAAABFVzZXIAAAABAAAH0AAAAARVc2Vy",
-  "AAAAAAAAAAAAAAAGY2hvaWNlAAAAAAABAAAAAAAAAAFjAAAAAAAH0AAAAAZDaG9pY2UAAAAAAAEAAAfQAAAABkNob2ljZQAA",
-  "AAAAAAAAAAAAAAAIdmVjX3VzZXIAAAABAAAAAAAAAAF2AAAAAAAD6gAAB9AAAAAEVXNlcgAAAAEAAAPqAAAH0AAAAARVc2Vy",
+  "AAAAAAAAAAAAAAAMbWFwX3UzMl9pMTI4AAAAAQAAAAAAAAABbQAAAAAAA+wAAAAEAAAACwAAAAEAAAPsAAAABAAAAAs=",
+  "AAAAAAAAAAAAAAAMbWFwX3VzZXJfdTMyAAAAAQAAAAAAAAABbQAAAAAAA+wAAAfQAAAABFVzZXIAAAAEAAAAAQAAA+wAAAfQAAAABFVzZXIAAAAE",
+  "AAAAAAAAAAAAAAAMdHVwbGVfbmVzdGVkAAAAAQAAAAAAAAABdgAAAAAAA+0AAAACAAAH0AAAAARVc2VyAAAD7QAAAAIAAAALAAAD6gAAAAQAAAABAAAD7QAAAAIAAAfQAAAABFVzZXIAAAPtAAAAAgAAAAsAAAPqAAAABA==",
+  "AAAAAAAAAAAAAAAMd2lkZV9udW1iZXJzAAAACgAAAAAAAAAFc21hbGwAAAAAAAAEAAAAAAAAAAZzaWduZWQAAAAAAAUAAAAAAAAAA2JpZwAAAAAGAAAAAAAAAAZvZmZzZXQAAAAAA+gAAAAHAAAAAAAAAAZzdXBwbHkAAAAAAAoAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAADY2FwAAAAA+gAAAAMAAAAAAAAAARkZWJ0AAAADQAAAAAAAAAIcHJpb3JpdHkAAAfQAAAACFByaW9yaXR5AAAAAAAAAARwYWlyAAAH0AAAAARQYWlyAAAAAQAAB9AAAAALV2lkZU51bWJlcnMA",
+  "AAAAAQAAAAAAAAAAAAAACk5lc3RlZFR5cGUAAAAAAAMAAAAAAAAABWRlcHRoAAAAAAAABAAAAAAAAAAGbmVzdGVkAAAAAAPqAAAH0AAAAApOZXN0ZWRUeXBlAAAAAAAAAAAABXdpZHRoAAAAAAAABA==",
+  "AAAAAAAAAAAAAAANYnVpbGRfbWFwX3UzMgAAAAAAAAAAAAABAAAD7AAAAAQAAAAL",
+  "AAAAAAAAAAAAAAANZW1pdF9iaWdfaW50cwAAAAAAAAQAAAAAAAAAAWEAAAAAAAAKAAAAAAAAAAFiAAAAAAAACwAAAAAAAAABYwAAAAAAAAwAAAAAAAAAAWQAAAAAAAANAAAAAA==",
+  "AAAAAAAAAAAAAAANZmFpbF9vdmVyZmxvdwAAAAAAAAIAAAAAAAAAAWEAAAAAAAAEAAAAAAAAAAFiAAAAAAAABAAAAAEAAAAE",
   "AAAAAAAAAAAAAAANbWFwX2FkZHJfdXNlcgAAAAAAAAEAAAAAAAAAAW0AAAAAAAPsAAAAEwAAB9AAAAAEVXNlcgAAAAEAAAPsAAAAEwAAB9AAAAAEVXNlcg==",
-  "AAAAAAAAAAAAAAAKb3B0aW9uX3UzMgAAAAAAAQAAAAAAAAABdgAAAAAAA+gAAAAEAAAAAQAAA+gAAAAE",
+  "AAAAAAAAAAAAAAANbWFwX2J5dGVzX3UzMgAAAAAAAAEAAAAAAAAAAW0AAAAAAAPsAAAADgAAAAQAAAABAAAD7AAAAA4AAAAE",
+  "AAAAAAAAAAAAAAANbWFwX3R1cGxlX3UzMgAAAAAAAAEAAAAAAAAAAW0AAAAAAAPsAAAD7QAAAAIAAAAEAAAAEQAAAAQAAAABAAAD7AAAA+0AAAACAAAABAAAABEAAAAE",
+  "AAAAAQAAAAAAAAAAAAAAC1dpZGVOdW1iZXJzAAAAAAoAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAADYmlnAAAAAAYAAAAAAAAAA2NhcAAAAAPoAAAADAAAAAAAAAAEZGVidAAAAA0AAAAAAAAABm9mZnNldAAAAAAD6AAAAAcAAAAAAAAABHBhaXIAAAfQAAAABFBhaXIAAAAAAAAACHByaW9yaXR5AAAH0AAAAAhQcmlvcml0eQAAAAAAAAAGc2lnbmVkAAAAAAAFAAAAAAAAAAVzbWFsbAAAAAAAAAQAAAAAAAAABnN1cHBseQAAAAAACg==",
+  "AAAAAAAAAAAAAAAOYnVpbGRfbWFwX2FkZHIAAAAAAAEAAAAAAAAABGtleXMAAAPqAAAAEwAAAAEAAAPsAAAAEwAAAAQ=",
+  "AAAAAAAAAAAAAAAOYnVpbGRfbWFwX2kxMjgAAAAAAAAAAAABAAAD7AAAAAsAAAAE",
+  "AAAAAAAAAAAAAAAOYnVpbGRfbWFwX3VzZXIAAAAAAAAAAAABAAAD7AAAB9AAAAAEVXNlcgAAAAQ=",
   "AAAAAAAAAAAAAAAOb3B0aW9uX2FkZHJlc3MAAAAAAAEAAAAAAAAAAXYAAAAAAAPoAAAAEwAAAAEAAAPoAAAAEw==",
-  "AAAAAAAAAAAAAAALb3B0aW9uX3VzZXIAAAAAAQAAAAAAAAABdgAAAAAAA+gAAAfQAAAABFVzZXIAAAABAAAD6AAAB9AAAAAEVXNlcg==",
-  "AAAAAAAAAAAAAAALbmVzdGVkX3R5cGUAAAAAAQAAAAAAAAABdgAAAAAAB9AAAAAKTmVzdGVkVHlwZQAAAAAAAQAAB9AAAAAKTmVzdGVkVHlwZQAA",
+  "AAAAAAAAAAAAAAAPYnVpbGRfbWFwX2J5dGVzAAAAAAAAAAABAAAD7AAAAA4AAAAE",
+  "AAAAAAAAAAAAAAAPYnVpbGRfbWFwX3R1cGxlAAAAAAAAAAABAAAD7AAAA+0AAAACAAAABAAAABEAAAAE",
+  "AAAAAAAAAAAAAAAPZmFpbF93aXRoX2Vycm9yAAAAAAEAAAAAAAAABGNvZGUAAAAEAAAAAA==",
+  "AAAAAAAAAAAAAAAQZW1pdF9uZXN0ZWRfdHlwZQAAAAEAAAAAAAAAAXYAAAAAAAfQAAAACk5lc3RlZFR5cGUAAAAAAAA=",
+  "AAAAAAAAAAAAAAAQbWFwX3N5bV92ZWNfYWRkcgAAAAEAAAAAAAAAAW0AAAAAAAPsAAAAEQAAA+oAAAATAAAAAQAAA+wAAAARAAAD6gAAABM=",
   "AAAAAAAAAAAAAAATZmxhdHRlbl9uZXN0ZWRfdHlwZQAAAAABAAAAAAAAAAF2AAAAAAAH0AAAAApOZXN0ZWRUeXBlAAAAAAABAAAD6gAAB9AAAAAKTmVzdGVkVHlwZQAA",
-  "AAAAAAAAAAAAAAAEZmFpbAAAAAEAAAAAAAAAC3Nob3VsZF9mYWlsAAAAAAEAAAAA",
+  "AAAAAAAAAAAAAAAUZmFpbF9taXNzaW5nX3N0b3JhZ2UAAAAAAAAAAA==",
+  "AAAAAAAAAAAAAAAXZmFpbF9pbnZhbGlkX2NvbnZlcnNpb24AAAAAAQAAAAAAAAABdgAAAAAAAAAAAAABAAAABA==",
+  "AAAAAAAAAAAAAAAYZmFpbF9pbmRleF9vdXRfb2ZfYm91bmRzAAAAAgAAAAAAAAABdgAAAAAAA+oAAAAEAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAABA==",
 ]);
 
 export enum TYPES_HARNESS_METHOD {
-  VOID = "void",
-  BOOL = "bool",
-  U32 = "u32",
+  ANY = "any",
   I32 = "i32",
-  U64 = "u64",
   I64 = "i64",
-  TIMEPOINT = "timepoint",
-  DURATION = "duration",
-  U128 = "u128",
+  U32 = "u32",
+  U64 = "u64",
+  BOOL = "bool",
+  EMIT = "emit",
+  FAIL = "fail",
   I128 = "i128",
-  U256 = "u256",
   I256 = "i256",
+  PAIR = "pair",
+  SWAP = "swap",
+  U128 = "u128",
+  U256 = "u256",
+  USER = "user",
+  VOID = "void",
+  WIDE = "wide",
   BYTES = "bytes",
+  LEVEL = "level",
+  TUPLE = "tuple",
+  CHOICE = "choice",
   STRING = "string",
   SYMBOL = "symbol",
   ADDRESS = "address",
+  VEC_ANY = "vec_any",
+  DURATION = "duration",
+  EMIT_MAP = "emit_map",
+  EMIT_VEC = "emit_vec",
+  PRIORITY = "priority",
   VEC_I128 = "vec_i128",
+  VEC_USER = "vec_user",
+  BYTES_N32 = "bytes_n32",
+  BYTES_N64 = "bytes_n64",
+  EMIT_USER = "emit_user",
+  FAIL_AUTH = "fail_auth",
+  TIMEPOINT = "timepoint",
+  FAIL_PANIC = "fail_panic",
+  OPTION_U32 = "option_u32",
+  RESULT_U32 = "result_u32",
+  EMIT_CHOICE = "emit_choice",
+  EMIT_TOPICS = "emit_topics",
+  FAIL_BUDGET = "fail_budget",
+  MAP_SYM_ANY = "map_sym_any",
+  NESTED_TYPE = "nested_type",
+  OPTION_USER = "option_user",
+  RESULT_USER = "result_user",
+  RESULT_VOID = "result_void",
   VEC_ADDRESS = "vec_address",
+  MAP_ADDR_U32 = "map_addr_u32",
+  MAP_I128_U32 = "map_i128_u32",
   MAP_SYM_I128 = "map_sym_i128",
-  MAP_SYM_VEC_ADDR = "map_sym_vec_addr",
-  ANY = "any",
-  VEC_ANY = "vec_any",
-  MAP_SYM_ANY = "map_sym_any",
-  USER = "user",
-  CHOICE = "choice",
-  VEC_USER = "vec_user",
+  MAP_U32_I128 = "map_u32_i128",
+  MAP_USER_U32 = "map_user_u32",
+  TUPLE_NESTED = "tuple_nested",
+  WIDE_NUMBERS = "wide_numbers",
+  BUILD_MAP_U32 = "build_map_u32",
+  EMIT_BIG_INTS = "emit_big_ints",
+  FAIL_OVERFLOW = "fail_overflow",
   MAP_ADDR_USER = "map_addr_user",
-  OPTION_U32 = "option_u32",
+  MAP_BYTES_U32 = "map_bytes_u32",
+  MAP_TUPLE_U32 = "map_tuple_u32",
+  BUILD_MAP_ADDR = "build_map_addr",
+  BUILD_MAP_I128 = "build_map_i128",
+  BUILD_MAP_USER = "build_map_user",
   OPTION_ADDRESS = "option_address",
-  OPTION_USER = "option_user",
-  NESTED_TYPE = "nested_type",
+  BUILD_MAP_BYTES = "build_map_bytes",
+  BUILD_MAP_TUPLE = "build_map_tuple",
+  FAIL_WITH_ERROR = "fail_with_error",
+  EMIT_NESTED_TYPE = "emit_nested_type",
+  MAP_SYM_VEC_ADDR = "map_sym_vec_addr",
   FLATTEN_NESTED_TYPE = "flatten_nested_type",
-  FAIL = "fail",
+  FAIL_MISSING_STORAGE = "fail_missing_storage",
+  FAIL_INVALID_CONVERSION = "fail_invalid_conversion",
+  FAIL_INDEX_OUT_OF_BOUNDS = "fail_index_out_of_bounds",
 }
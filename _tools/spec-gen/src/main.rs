@@ -0,0 +1,191 @@
+// Generates the TS spec fixtures in `_internal/tests/specs` from the
+// `contractspecv0` section of the compiled contracts.
+//
+//   cargo run -p spec-gen                   write every workspace fixture
+//   cargo run -p spec-gen -- --check        fail if a committed fixture is stale
+//   cargo run -p spec-gen -- types-harness  only the named targets
+//
+// Contracts are read from `target/wasm32v1-none/release`, or `--wasm-dir`.
+// External targets are only generated when named.
+
+mod render;
+mod test;
+mod wasm;
+
+use render::{render, MethodCase};
+use std::{
+    env, fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry, WriteXdr};
+
+const SPEC_SECTION: &str = "contractspecv0";
+
+struct Target {
+    name: &'static str,
+    wasm: &'static str,
+    output: &'static str,
+    prefix: &'static str,
+    method_case: MethodCase,
+    // Built outside this workspace
+    external: bool,
+}
+
+const TARGETS: &[Target] = &[
+    Target {
+        name: "types-harness",
+        wasm: "types_harness.wasm",
+        output: "_internal/tests/specs/types-harness.ts",
+        prefix: "TYPES_HARNESS",
+        method_case: MethodCase::Upper,
+        external: false,
+    },
+    Target {
+        name: "errors-harness",
+        wasm: "errors_harness.wasm",
+        output: "_internal/tests/specs/errors-harness.ts",
+        prefix: "ERRORS_HARNESS",
+        method_case: MethodCase::Upper,
+        external: false,
+    },
+    Target {
+        name: "fungible-token",
+        wasm: "fungible_token_contract.wasm",
+        output: "_internal/tests/specs/fungible-token.ts",
+        prefix: "FT",
+        method_case: MethodCase::Preserve,
+        external: false,
+    },
+    // The Stellar token example contract, which is not part of this workspace;
+    // build it separately and point `--wasm-dir` at its output
+    Target {
+        name: "sep41",
+        wasm: "soroban_token_contract.wasm",
+        output: "_internal/tests/specs/sep41.ts",
+        prefix: "SEP41",
+        method_case: MethodCase::Preserve,
+        external: true,
+    },
+];
+
+struct Args {
+    check: bool,
+    wasm_dir: PathBuf,
+    targets: Vec<&'static Target>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Returns false if `--check` found a stale fixture
+fn run() -> Result<bool, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let args = parse_args(&root)?;
+
+    let mut fresh = true;
+    for target in &args.targets {
+        let wasm_path = args.wasm_dir.join(target.wasm);
+        let wasm = fs::read(&wasm_path)
+            .map_err(|e| format!("{}: reading {}: {e}", target.name, wasm_path.display()))?;
+        let module = generate(target, &wasm).map_err(|e| format!("{}: {e}", target.name))?;
+
+        let output = root.join(target.output);
+        let current = fs::read_to_string(&output).ok();
+        if current.as_deref() == Some(module.as_str()) {
+            println!("{}: up to date", target.output);
+        } else if args.check {
+            println!("{}: stale", target.output);
+            fresh = false;
+        } else {
+            fs::write(&output, module)
+                .map_err(|e| format!("{}: writing {}: {e}", target.name, output.display()))?;
+            println!("{}: written", target.output);
+        }
+    }
+
+    if !fresh {
+        eprintln!("run `cargo run -p spec-gen` to regenerate the stale fixtures");
+    }
+    Ok(fresh)
+}
+
+fn parse_args(root: &Path) -> Result<Args, String> {
+    let mut check = false;
+    let mut wasm_dir = root.join("target/wasm32v1-none/release");
+    let mut targets = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--wasm-dir" => {
+                wasm_dir = args.next().ok_or("--wasm-dir expects a directory")?.into();
+            }
+            name => {
+                let target = TARGETS
+                    .iter()
+                    .find(|t| t.name == name)
+                    .ok_or_else(|| format!("unknown target `{name}`"))?;
+                targets.push(target);
+            }
+        }
+    }
+    if targets.is_empty() {
+        targets = TARGETS.iter().filter(|t| !t.external).collect();
+    }
+
+    Ok(Args {
+        check,
+        wasm_dir,
+        targets,
+    })
+}
+
+fn generate(target: &Target, wasm: &[u8]) -> Result<String, String> {
+    let section = wasm::custom_section(wasm, SPEC_SECTION)?;
+    if section.is_empty() {
+        return Err(format!("no {SPEC_SECTION} section"));
+    }
+    let entries = read_entries(&section)?;
+
+    let encoded = entries
+        .iter()
+        .map(|entry| entry.to_xdr_base64(Limits::none()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("encoding spec entry: {e}"))?;
+    Ok(render(
+        target.prefix,
+        &encoded,
+        &method_names(&entries),
+        target.method_case,
+    ))
+}
+
+fn read_entries(section: &[u8]) -> Result<Vec<ScSpecEntry>, String> {
+    let mut reader = Limited::new(Cursor::new(section), Limits::none());
+    ScSpecEntry::read_xdr_iter(&mut reader)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("decoding {SPEC_SECTION}: {e}"))
+}
+
+// Functions in spec order, leaving out reserved ones such as `__constructor`
+fn method_names(entries: &[ScSpecEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry {
+            ScSpecEntry::FunctionV0(f) => Some(f.name.0.to_utf8_string_lossy()),
+            _ => None,
+        })
+        .filter(|name| !name.starts_with("__"))
+        .collect()
+}
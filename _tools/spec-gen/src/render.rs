@@ -0,0 +1,30 @@
+// Renders a spec as a TS module in the layout of `_internal/tests/specs`
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MethodCase {
+    // `TRANSFER_FROM = "transfer_from"`
+    Upper,
+    // `transfer_from = "transfer_from"`
+    Preserve,
+}
+
+pub fn render(prefix: &str, entries: &[String], methods: &[String], case: MethodCase) -> String {
+    let mut out = String::new();
+    out.push_str("// deno-coverage-ignore-file\n\n");
+    out.push_str("import { Spec } from \"stellar-sdk/contract\";\n");
+    out.push_str(&format!("export const {prefix}_SPEC = new Spec([\n"));
+    for entry in entries {
+        out.push_str(&format!("  \"{entry}\",\n"));
+    }
+    out.push_str("]);\n\n");
+    out.push_str(&format!("export enum {prefix}_METHOD {{\n"));
+    for method in methods {
+        let member = match case {
+            MethodCase::Upper => method.to_uppercase(),
+            MethodCase::Preserve => method.clone(),
+        };
+        out.push_str(&format!("  {member} = \"{method}\",\n"));
+    }
+    out.push_str("}\n");
+    out
+}
@@ -0,0 +1,166 @@
+#![cfg(test)]
+use crate::{
+    generate, method_names,
+    render::{render, MethodCase},
+    wasm::custom_section,
+    Target, SPEC_SECTION,
+};
+use stellar_xdr::curr::{
+    Limits, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef, ScSpecUdtErrorEnumCaseV0,
+    ScSpecUdtErrorEnumV0, ScSymbol, VecM, WriteXdr,
+};
+
+const TARGET: Target = Target {
+    name: "test",
+    wasm: "test.wasm",
+    output: "test.ts",
+    prefix: "TEST",
+    method_case: MethodCase::Upper,
+    external: false,
+};
+
+fn function(name: &str) -> ScSpecEntry {
+    ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        doc: "".try_into().unwrap(),
+        name: ScSymbol(name.try_into().unwrap()),
+        inputs: VecM::default(),
+        outputs: vec![ScSpecTypeDef::U32].try_into().unwrap(),
+    })
+}
+
+fn error_enum() -> ScSpecEntry {
+    ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+        doc: "".try_into().unwrap(),
+        lib: "".try_into().unwrap(),
+        name: "Error".try_into().unwrap(),
+        cases: vec![ScSpecUdtErrorEnumCaseV0 {
+            doc: "".try_into().unwrap(),
+            name: "NotFound".try_into().unwrap(),
+            value: 1,
+        }]
+        .try_into()
+        .unwrap(),
+    })
+}
+
+fn leb128(mut v: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn section(id: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![id];
+    out.extend(leb128(content.len()));
+    out.extend_from_slice(content);
+    out
+}
+
+fn custom(name: &str, content: &[u8]) -> Vec<u8> {
+    let mut body = leb128(name.len());
+    body.extend_from_slice(name.as_bytes());
+    body.extend_from_slice(content);
+    section(0, &body)
+}
+
+fn module(sections: &[Vec<u8>]) -> Vec<u8> {
+    let mut out = b"\0asm\x01\0\0\0".to_vec();
+    for s in sections {
+        out.extend_from_slice(s);
+    }
+    out
+}
+
+fn spec_bytes(entries: &[ScSpecEntry]) -> Vec<u8> {
+    entries
+        .iter()
+        .flat_map(|e| e.to_xdr(Limits::none()).unwrap())
+        .collect()
+}
+
+#[test]
+fn reads_custom_section_among_others() {
+    let wasm = module(&[
+        section(1, &[0x60, 0, 0]),
+        custom("name", b"ignored"),
+        custom(SPEC_SECTION, b"first"),
+        section(10, &[0; 200]),
+        custom(SPEC_SECTION, b"second"),
+    ]);
+
+    assert_eq!(
+        custom_section(&wasm, SPEC_SECTION).unwrap(),
+        b"firstsecond".to_vec()
+    );
+    assert!(custom_section(&wasm, "missing").unwrap().is_empty());
+}
+
+#[test]
+fn rejects_malformed_modules() {
+    assert!(custom_section(b"not wasm", SPEC_SECTION).is_err());
+
+    let mut truncated = module(&[custom(SPEC_SECTION, b"spec")]);
+    truncated.pop();
+    assert!(custom_section(&truncated, SPEC_SECTION).is_err());
+}
+
+#[test]
+fn renders_fixture_layout() {
+    let entries = vec!["AAAA".to_string(), "BBBB".to_string()];
+    let methods = vec!["transfer_from".to_string()];
+
+    assert_eq!(
+        render("FT", &entries, &methods, MethodCase::Preserve),
+        "// deno-coverage-ignore-file\n\
+         \n\
+         import { Spec } from \"stellar-sdk/contract\";\n\
+         export const FT_SPEC = new Spec([\n  \"AAAA\",\n  \"BBBB\",\n]);\n\
+         \n\
+         export enum FT_METHOD {\n  transfer_from = \"transfer_from\",\n}\n"
+    );
+    assert!(render("FT", &entries, &methods, MethodCase::Upper)
+        .contains("  TRANSFER_FROM = \"transfer_from\",\n"));
+}
+
+#[test]
+fn methods_skip_reserved_functions_and_types() {
+    let entries = vec![
+        error_enum(),
+        function("__constructor"),
+        function("balance"),
+        function("__check_auth"),
+        function("transfer"),
+    ];
+
+    assert_eq!(method_names(&entries), vec!["balance", "transfer"]);
+}
+
+#[test]
+fn generates_module_from_wasm() {
+    let entries = vec![error_enum(), function("__constructor"), function("u32")];
+    let wasm = module(&[custom(SPEC_SECTION, &spec_bytes(&entries))]);
+
+    let module = generate(&TARGET, &wasm).unwrap();
+
+    for entry in &entries {
+        let encoded = entry.to_xdr_base64(Limits::none()).unwrap();
+        assert!(module.contains(&format!("  \"{encoded}\",\n")));
+    }
+    assert!(module.contains("export enum TEST_METHOD {\n  U32 = \"u32\",\n}\n"));
+}
+
+#[test]
+fn generate_fails_without_spec() {
+    let wasm = module(&[custom("name", b"x")]);
+
+    assert!(generate(&TARGET, &wasm).is_err());
+    let garbage = module(&[custom(SPEC_SECTION, &[0xff; 3])]);
+    assert!(generate(&TARGET, &garbage).is_err());
+}
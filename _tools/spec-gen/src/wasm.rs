@@ -0,0 +1,53 @@
+// Minimal reader for the custom sections of a wasm module; only the section
+// framing is parsed, the rest of the module is skipped over
+
+const MAGIC: &[u8] = b"\0asm";
+const VERSION: &[u8] = &[1, 0, 0, 0];
+const CUSTOM_SECTION_ID: u8 = 0;
+
+// Contents of every custom section called `name`, concatenated in module order
+pub fn custom_section(wasm: &[u8], name: &str) -> Result<Vec<u8>, String> {
+    if wasm.len() < 8 || &wasm[..4] != MAGIC || &wasm[4..8] != VERSION {
+        return Err("not a wasm module".into());
+    }
+
+    let mut out = Vec::new();
+    let mut pos = 8;
+    while pos < wasm.len() {
+        let id = wasm[pos];
+        pos += 1;
+        let size = read_u32(wasm, &mut pos)? as usize;
+        let end = pos
+            .checked_add(size)
+            .filter(|end| *end <= wasm.len())
+            .ok_or("section runs past the end of the module")?;
+
+        if id == CUSTOM_SECTION_ID {
+            let mut name_pos = pos;
+            let name_len = read_u32(wasm, &mut name_pos)? as usize;
+            let name_end = name_pos
+                .checked_add(name_len)
+                .filter(|name_end| *name_end <= end)
+                .ok_or("custom section name runs past the section")?;
+            if &wasm[name_pos..name_end] == name.as_bytes() {
+                out.extend_from_slice(&wasm[name_end..end]);
+            }
+        }
+        pos = end;
+    }
+    Ok(out)
+}
+
+// Unsigned LEB128
+fn read_u32(bytes: &[u8], pos: &mut usize) -> Result<u32, String> {
+    let mut result: u32 = 0;
+    for shift in (0..35).step_by(7) {
+        let byte = *bytes.get(*pos).ok_or("truncated LEB128 integer")?;
+        *pos += 1;
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err("LEB128 integer is too long".into())
+}
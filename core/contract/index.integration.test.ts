@@ -1,6 +1,12 @@
 import { disableSanitizeConfig } from "colibri-internal/tests/disable-sanitize-config.ts";
 import { loadWasmFile } from "colibri-internal/util/load-wasm-file.ts";
-import { assert, assertEquals, assertExists, assertRejects } from "@std/assert";
+import {
+  assert,
+  assertEquals,
+  assertExists,
+  assertRejects,
+  assertStringIncludes,
+} from "@std/assert";
 import { beforeAll, describe, it } from "@std/testing/bdd";
 import { Buffer } from "buffer";
 import { nativeToScVal, xdr } from "stellar-sdk";
@@ -16,6 +22,7 @@ import {
 } from "colibri-internal/tests/specs/types-harness.ts";
 import { StrKey } from "@/strkeys/index.ts";
 import * as E from "@/contract/error.ts";
+import { SIMULATION_FAILED } from "@/processes/simulate-transaction/error.ts";
 import type { TransactionConfig } from "@/common/types/transaction-config/types.ts";
 
 describe("[Testnet] Contract", disableSanitizeConfig, () => {
@@ -40,6 +47,8 @@ describe("[Testnet] Contract", disableSanitizeConfig, () => {
   describe("Core features and initialization", () => {
     let wasm: Buffer;
     let wasmFt: Buffer;
+    let wasmResource: Buffer;
+    let wasmDepth: Buffer;
     let wasmHash: string;
     let typesHarnessContractId: string;
 
@@ -51,6 +60,14 @@ describe("[Testnet] Contract", disableSanitizeConfig, () => {
       wasmFt = await loadWasmFile(
         "./_internal/tests/compiled-contracts/fungible_token_contract.wasm"
       );
+
+      wasmResource = await loadWasmFile(
+        "./_internal/tests/compiled-contracts/resource_harness.wasm"
+      );
+
+      wasmDepth = await loadWasmFile(
+        "./_internal/tests/compiled-contracts/depth_harness.wasm"
+      );
     });
     it("Initializes with WASM and upload binaries", async () => {
       const contract = new Contract({
@@ -101,6 +118,10 @@ describe("[Testnet] Contract", disableSanitizeConfig, () => {
         constructorArgs: {
           recipient: admin.address(),
           owner: admin.address(),
+          name: "ColibriToken",
+          symbol: "CLBT",
+          decimals: 7,
+          initial_supply: 1_000_000_0000000n,
         },
       });
 
@@ -277,6 +298,90 @@ describe("[Testnet] Contract", disableSanitizeConfig, () => {
           E.FAILED_TO_DEPLOY_CONTRACT
         );
       });
+
+      it("throws SIMULATION_FAILED when a call exhausts the budget", async () => {
+        const contract = new Contract({
+          networkConfig,
+          contractConfig: {
+            contractId: typesHarnessContractId,
+            spec: TYPES_HARNESS_SPEC,
+          },
+        });
+
+        const error = await assertRejects(
+          async () =>
+            await contract.read({
+              method: TYPES_HARNESS_METHOD.FAIL_BUDGET,
+              methodArgs: { iterations: 1_000_000 },
+            }),
+          SIMULATION_FAILED
+        );
+
+        assertStringIncludes(
+          error.meta.data.simulationResponse.error,
+          "Error(Budget, ExceededLimit)"
+        );
+      });
+
+      it("throws SIMULATION_FAILED when an allocation exhausts the memory budget", async () => {
+        const contract = new Contract({
+          networkConfig,
+          contractConfig: {
+            wasm: wasmResource,
+          },
+        });
+
+        await contract.uploadWasm(config);
+        await contract.deploy({ config: config });
+        await contract.loadSpecFromDeployedContract();
+
+        const error = await assertRejects(
+          async () =>
+            await contract.read({
+              method: "alloc",
+              methodArgs: { bytes: 4_294_967_295 },
+            }),
+          SIMULATION_FAILED
+        );
+
+        assertStringIncludes(
+          error.meta.data.simulationResponse.error,
+          "Error(Budget, ExceededLimit)"
+        );
+      });
+
+      it("throws SIMULATION_FAILED when a value exceeds the host depth limit", async () => {
+        const contract = new Contract({
+          networkConfig,
+          contractConfig: {
+            wasm: wasmDepth,
+          },
+        });
+
+        await contract.uploadWasm(config);
+        await contract.deploy({ config: config });
+        await contract.loadSpecFromDeployedContract();
+
+        // 49 levels fit within the limit of 100; 51 go past it
+        await contract.read({
+          method: "nest_to_xdr",
+          methodArgs: { depth: 49 },
+        });
+
+        const error = await assertRejects(
+          async () =>
+            await contract.read({
+              method: "nest_to_xdr",
+              methodArgs: { depth: 51 },
+            }),
+          SIMULATION_FAILED
+        );
+
+        assertStringIncludes(
+          error.meta.data.simulationResponse.error,
+          "ExceededLimit"
+        );
+      });
     });
   });
 });